@@ -0,0 +1,240 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let years: Vec<Year> = numbered_dirs(&src, "year")
+        .into_iter()
+        .map(|(number, dir)| Year {
+            number,
+            days: numbered_dirs(&dir, "day")
+                .into_iter()
+                .filter(|(_, dir)| dir.join("mod.rs").exists())
+                .map(|(day, dir)| (day as u8, dir))
+                .collect(),
+        })
+        .filter(|year| !year.days.is_empty())
+        .collect();
+    generate_day_modules(&years);
+    generate_example_tests(&years);
+}
+
+/// A `src/yearYYYY` directory and its `dayN` directories, sorted by day.
+struct Year {
+    number: u16,
+    days: Vec<(u8, PathBuf)>,
+}
+
+/// Every directory in `dir` named `prefix` followed by a number, sorted by that number.
+fn numbered_dirs(dir: &Path, prefix: &str) -> Vec<(u16, PathBuf)> {
+    let mut dirs: Vec<(u16, _)> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("{} is readable: {err}", dir.display()))
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .parse()
+                .ok()?;
+            path.is_dir().then_some((number, path))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Writes `$OUT_DIR/days.rs`, declaring a `yearYYYY` module for every year directory with a
+/// module for each of its day directories, and `$OUT_DIR/registrations.rs`, listing their
+/// `REGISTRATION`s, so adding a day only takes a new `src/yearYYYY/dayN/mod.rs`. With
+/// `embed-inputs` it also embeds every day's inputs.
+fn generate_day_modules(years: &[Year]) {
+    let mut modules = String::new();
+    for year in years {
+        writeln!(
+            modules,
+            "pub mod year{0} {{\n    const YEAR: u16 = {0};\n",
+            year.number
+        )
+        .unwrap();
+        for (day, dir) in &year.days {
+            let path = dir.join("mod.rs");
+            writeln!(modules, "    #[path = {path:?}]\n    pub mod day{day};").unwrap();
+        }
+        writeln!(modules, "}}\n").unwrap();
+    }
+
+    let mut registrations = String::new();
+    writeln!(registrations, "const YEARS: &[Year] = &[").unwrap();
+    for year in years {
+        writeln!(
+            registrations,
+            "    Year {{\n        number: {},\n        days: &[",
+            year.number
+        )
+        .unwrap();
+        for (day, _) in &year.days {
+            writeln!(
+                registrations,
+                "            crate::year{}::day{day}::REGISTRATION,",
+                year.number
+            )
+            .unwrap();
+        }
+        writeln!(registrations, "        ],\n    }},").unwrap();
+    }
+    writeln!(registrations, "];").unwrap();
+
+    writeln!(
+        registrations,
+        "\n#[cfg(feature = \"embed-inputs\")]\n\
+         pub fn embedded(year: u16, day: u8, input_type: &crate::InputType) -> Option<&'static str> {{\n    \
+         match (year, day, input_type) {{"
+    )
+    .unwrap();
+    for year in years {
+        for (day, dir) in &year.days {
+            let path = dir.join("input");
+            writeln!(
+                registrations,
+                "        ({}, {day}, crate::InputType::Actual) => Some(include_str!({path:?})),",
+                year.number
+            )
+            .unwrap();
+            for example in examples(dir) {
+                let path = &example.input;
+                let pattern = match &example.name {
+                    None => "(None))".to_string(),
+                    Some(name) => format!("(Some(name))) if name == {name:?}"),
+                };
+                writeln!(
+                    registrations,
+                    "        ({}, {day}, crate::InputType::Example{pattern} => Some(include_str!({path:?})),",
+                    year.number
+                )
+                .unwrap();
+            }
+        }
+    }
+    writeln!(registrations, "        _ => None,\n    }}\n}}").unwrap();
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("days.rs"), modules).unwrap();
+    fs::write(Path::new(&out_dir).join("registrations.rs"), registrations).unwrap();
+}
+
+/// Writes one test per year, day, example and part to `$OUT_DIR/example_tests.rs`, checking each
+/// example input against the answers in its sidecar. Parts without an expected answer (or with
+/// an empty one, as scaffolded by `new-day`), and empty examples, become ignored tests so they
+/// show up as skipped.
+fn generate_example_tests(years: &[Year]) {
+    let mut tests = String::new();
+    for (year, day, dir) in years.iter().flat_map(|year| {
+        year.days
+            .iter()
+            .map(move |(day, dir)| (year.number, *day, dir))
+    }) {
+        for example in examples(dir) {
+            let input_path = &example.input;
+            let input = fs::read_to_string(input_path).unwrap_or_default();
+            let answers = fs::read_to_string(&example.answers).unwrap_or_default();
+            let (suffix, description) = match &example.name {
+                None => (String::new(), format!("{year} day{day}")),
+                Some(name) => (
+                    format!("_{}", identifier(name)),
+                    format!("{year} day{day} example {name}"),
+                ),
+            };
+
+            for part in [1, 2] {
+                let name = format!("year{year}_day{day}_part{part}_example{suffix}");
+                let expected = answers.lines().find_map(|line| {
+                    let (key, value) = line.split_once(':')?;
+                    (key.trim() == format!("part{part}")).then(|| value.trim().to_string())
+                });
+                let expected = expected.filter(|expected| !expected.is_empty());
+                let skip_reason = if input.trim().is_empty() {
+                    Some(format!("{description} has no example input"))
+                } else if expected.is_none() {
+                    Some(format!(
+                        "{description} has no expected answer for part {part}"
+                    ))
+                } else {
+                    None
+                };
+
+                match (skip_reason, expected) {
+                    (None, Some(expected)) => writeln!(
+                        tests,
+                        "#[test]\nfn {name}() {{\n    check({year}, {day}, {part}, include_str!({input_path:?}), {expected:?});\n}}\n"
+                    ),
+                    (reason, _) => writeln!(
+                        tests,
+                        "#[test]\n#[ignore = {reason:?}]\nfn {name}() {{}}\n",
+                        reason = reason.unwrap_or_default()
+                    ),
+                }
+                .unwrap();
+            }
+        }
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("example_tests.rs"), tests).unwrap();
+}
+
+/// `name` as the end of a test function name, distinct for every name: letters and digits stay,
+/// `_` doubles and anything else becomes `_` and its hex code point, so `a-b` is `a_2d_b` and
+/// `a_b` is `a__b`.
+fn identifier(name: &str) -> String {
+    let mut identifier = String::new();
+    for c in name.chars() {
+        match c {
+            c if c.is_ascii_alphanumeric() => identifier.push(c),
+            '_' => identifier.push_str("__"),
+            c => identifier.push_str(&format!("_{:x}_", c as u32)),
+        }
+    }
+    identifier
+}
+
+/// An example input of a day, with the sidecar holding its expected answers.
+struct Example {
+    /// `None` for the default `example_input`, the `<name>` of `example_input_<name>` otherwise.
+    name: Option<String>,
+    input: PathBuf,
+    answers: PathBuf,
+}
+
+/// Every example in a day directory, the default `example_input` first, then the named ones
+/// sorted by name.
+fn examples(dir: &Path) -> Vec<Example> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .expect("day directory is readable")
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name();
+            let name = file_name.to_str()?.strip_prefix("example_input_")?;
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect();
+    names.sort();
+
+    std::iter::once(None)
+        .chain(names.into_iter().map(Some))
+        .map(|name| {
+            let suffix = name
+                .as_ref()
+                .map(|name| format!("_{name}"))
+                .unwrap_or_default();
+            Example {
+                input: dir.join(format!("example_input{suffix}")),
+                answers: dir.join(format!("example_answers{suffix}")),
+                name,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,239 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize, de::Visitor};
+
+/// The answer to one part of a puzzle.
+///
+/// Numeric answers compare by value, so `Unsigned(5)`, `Signed(5)` and `BigInt(5)` are equal.
+#[derive(Debug, Clone, Default)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    BigInt(i128),
+    Text(String),
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    /// The value of a numeric answer, or `None` for text and unsolved answers.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(n) => Some(i128::from(*n)),
+            Answer::Signed(n) => Some(i128::from(*n)),
+            Answer::BigInt(n) => Some(*n),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    /// The smallest numeric variant that holds `n`.
+    fn from_i128(n: i128) -> Self {
+        if let Ok(n) = u64::try_from(n) {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = i64::try_from(n) {
+            Answer::Signed(n)
+        } else {
+            Answer::BigInt(n)
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (a, b) => a.as_i128().is_some() && a.as_i128() == b.as_i128(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// Parses integers into the smallest numeric variant and anything else into [`Answer::Text`].
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(match s.parse::<i128>() {
+            Ok(n) => Answer::from_i128(n),
+            Err(_) if s.is_empty() => Answer::Unsolved,
+            Err(_) => Answer::Text(s.into()),
+        })
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident as $target:ty: $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Unsigned as u64: u8, u16, u32, u64, usize);
+impl_from_int!(Signed as i64: i8, i16, i32, i64, isize);
+impl_from_int!(BigInt as i128: i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.into())
+    }
+}
+
+/// Numbers serialize as JSON numbers, text as a string and unsolved answers as `null`. Numbers
+/// beyond 64 bits, which JSON readers would round through floating point, are written as strings
+/// and read back as numbers.
+impl Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Signed(n) => serializer.serialize_i64(*n),
+            Answer::BigInt(n) => match Answer::from_i128(*n) {
+                Answer::Unsigned(n) => serializer.serialize_u64(n),
+                Answer::Signed(n) => serializer.serialize_i64(n),
+                _ => serializer.serialize_str(&n.to_string()),
+            },
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "an integer, a string or null")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Answer, E> {
+                Ok(Answer::Unsigned(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Answer, E> {
+                Ok(Answer::from_i128(i128::from(v)))
+            }
+
+            fn visit_i128<E>(self, v: i128) -> Result<Answer, E> {
+                Ok(Answer::from_i128(v))
+            }
+
+            fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Answer, E> {
+                i128::try_from(v)
+                    .map(Answer::from_i128)
+                    .map_err(|_| E::custom(format!("answer {v} does not fit in an i128")))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Answer, E> {
+                // Only numbers beyond 64 bits are written as strings, so text such as "1234" or
+                // "007" stays text.
+                match v.parse::<i128>().map(Answer::from_i128) {
+                    Ok(Answer::BigInt(n)) if n.to_string() == v => Ok(Answer::BigInt(n)),
+                    _ => Ok(Answer::Text(v.into())),
+                }
+            }
+
+            fn visit_unit<E>(self) -> Result<Answer, E> {
+                Ok(Answer::Unsolved)
+            }
+
+            fn visit_none<E>(self) -> Result<Answer, E> {
+                Ok(Answer::Unsolved)
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn round_trip(answer: &Answer) -> Answer {
+        serde_json::from_str(&serde_json::to_string(answer).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_the_smallest_variant() {
+        let parse = |s: &str| s.parse::<Answer>().unwrap();
+        assert!(matches!(parse("42"), Answer::Unsigned(42)));
+        assert!(matches!(parse(" -7\n"), Answer::Signed(-7)));
+        assert!(matches!(parse("100000000000000000000"), Answer::BigInt(_)));
+        assert_eq!(parse("abc"), Answer::Text("abc".into()));
+        assert_eq!(parse("  "), Answer::Unsolved);
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::Unsigned(5), Answer::Signed(5));
+        assert_eq!(Answer::Signed(5), Answer::BigInt(5));
+        assert_ne!(Answer::Unsigned(5), Answer::Text("5".into()));
+        assert_ne!(Answer::Unsolved, Answer::Text(String::new()));
+        assert_eq!(Answer::Unsolved, Answer::Unsolved);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        for answer in [
+            Answer::Unsigned(u64::MAX),
+            Answer::Signed(i64::MIN),
+            Answer::BigInt(100_000_000_000_000_000_000),
+            Answer::BigInt(-100_000_000_000_000_000_000),
+            Answer::BigInt(12),
+            Answer::Text("ABC".into()),
+            Answer::Text("007".into()),
+            Answer::Text("1234".into()),
+            Answer::Text("-5".into()),
+            Answer::Unsolved,
+        ] {
+            let back = round_trip(&answer);
+            assert_eq!(back, answer);
+            assert_eq!(back.to_string(), answer.to_string());
+        }
+        assert_eq!(
+            serde_json::to_string(&Answer::BigInt(i128::MAX)).unwrap(),
+            format!("\"{}\"", i128::MAX)
+        );
+
+        // Big numbers nested in maps, the way ledgers store them.
+        let ledger = BTreeMap::from([(1u8, Answer::BigInt(-(1 << 100)))]);
+        let json = serde_json::to_string_pretty(&ledger).unwrap();
+        let back: BTreeMap<u8, Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, ledger);
+    }
+}
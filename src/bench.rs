@@ -0,0 +1,207 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    InputType, Part,
+    input::{self, InputSource},
+    registry::Registration,
+    runner::{self, Outcome},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub warmup: usize,
+    /// Relative slowdown of the median, in percent, that counts as a regression.
+    pub threshold: f64,
+}
+
+/// Timing statistics in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        let mut samples: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        samples.sort_by(f64::total_cmp);
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        let mid = samples.len() / 2;
+        let median = match samples.len() % 2 {
+            0 => (samples[mid - 1] + samples[mid]) / 2.0,
+            _ => samples[mid],
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:>10}  median {:>10}  mean {:>10}  stddev {:>10}",
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.mean),
+            format_nanos(self.stddev),
+        )
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub input_type: String,
+    pub results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        serde_json::from_str(&contents)
+            .map_err(|err| format!("invalid benchmark file {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).expect("report is serializable");
+        std::fs::write(path, contents)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+
+    /// Prints the change in median parse and solve time for every result that also appears in
+    /// `baseline`, returning whether any of them slowed down by more than `threshold` percent.
+    pub fn compare(&self, baseline: &BenchReport, threshold: f64) -> bool {
+        let mut regressed = false;
+        for result in self.results.iter() {
+            let Some(old) = baseline
+                .results
+                .iter()
+                .find(|old| old.day == result.day && old.part == result.part)
+            else {
+                continue;
+            };
+
+            println!("Day {} part {} vs baseline", result.day, result.part);
+            for (name, new, old) in [
+                ("parse", result.parse, old.parse),
+                ("solve", result.solve, old.solve),
+            ] {
+                let change = (new.median - old.median) / old.median.max(1.0) * 100.0;
+                let marker = if change > threshold {
+                    regressed = true;
+                    "  REGRESSION"
+                } else {
+                    ""
+                };
+                println!(
+                    "  {name}  median {:>10} -> {:>10}  {change:+.1}%{marker}",
+                    format_nanos(old.median),
+                    format_nanos(new.median),
+                );
+            }
+        }
+        regressed
+    }
+}
+
+/// Benchmarks every selected part, timing the parse and the solve of each iteration separately.
+pub fn bench(
+    days: &[&Registration],
+    parts: &[Part],
+    input_type: &InputType,
+    source: &InputSource,
+    options: BenchOptions,
+) -> BenchReport {
+    let mut results = vec![];
+    for day in days {
+        let input = match input::load(day.year, day.number, input_type, source) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("Day {}: {err}", day.number);
+                continue;
+            }
+        };
+        let solver = day.solver();
+
+        for part in parts {
+            // The first warm-up run also checks that the day parses and the part runs at all.
+            let outcome = match runner::catch_panic(|| solver.parse(&input)) {
+                Ok(Ok(parsed)) => runner::solve_part(solver.as_ref(), parsed.as_ref(), *part),
+                Ok(Err(err)) => Outcome::InvalidInput(err.with_day(day.number)),
+                Err(message) => Outcome::Panicked(message),
+            };
+            if !matches!(outcome, Outcome::Answer(_)) {
+                println!("Day {} part {}: {outcome}", day.number, part.number());
+                continue;
+            }
+            for _ in 1..options.warmup {
+                let parsed = solver.parse(&input).expect("input parsed during warm-up");
+                black_box(solver.solve(parsed.as_ref(), *part));
+            }
+
+            let mut parse_samples = Vec::with_capacity(options.iterations);
+            let mut solve_samples = Vec::with_capacity(options.iterations);
+            for _ in 0..options.iterations {
+                let start = Instant::now();
+                let parsed = black_box(solver.parse(&input)).expect("input parsed during warm-up");
+                parse_samples.push(start.elapsed());
+
+                let start = Instant::now();
+                black_box(solver.solve(parsed.as_ref(), *part));
+                solve_samples.push(start.elapsed());
+            }
+
+            let result = BenchResult {
+                day: day.number,
+                part: part.number(),
+                iterations: options.iterations,
+                parse: Stats::from_samples(&parse_samples),
+                solve: Stats::from_samples(&solve_samples),
+            };
+            println!(
+                "Day {} part {} ({} iterations)",
+                result.day, result.part, result.iterations
+            );
+            println!("  parse  {}", result.parse);
+            println!("  solve  {}", result.solve);
+            results.push(result);
+        }
+    }
+
+    BenchReport {
+        input_type: input_type.to_string(),
+        results,
+    }
+}
@@ -0,0 +1,283 @@
+//! Talks to the Advent of Code website: downloading inputs and submitting answers, politely.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ureq::Agent;
+
+use crate::{InputType, answer::Answer, input};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Connection settings shared by the subcommands that talk to the website.
+#[derive(clap::Args, Debug)]
+pub struct ClientArgs {
+    /// Root of the Advent of Code website
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Session cookie of a logged in browser, takes precedence over --session-file
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// File holding the session cookie, defaults to `~/.config/aoc/session`
+    #[arg(long, env = "AOC_SESSION_FILE")]
+    session_file: Option<PathBuf>,
+
+    /// Minimum number of seconds between two requests to the website
+    #[arg(long, default_value_t = 5)]
+    interval: u64,
+}
+
+impl ClientArgs {
+    pub fn client(&self) -> Result<Client, String> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => {
+                let path = self
+                    .session_file
+                    .clone()
+                    .or_else(default_session_file)
+                    .ok_or(
+                        "no session token: set AOC_SESSION or pass --session-file".to_string(),
+                    )?;
+                fs::read_to_string(&path)
+                    .map_err(|err| {
+                        format!(
+                            "no session token: set AOC_SESSION or write it to {} ({err})",
+                            path.display()
+                        )
+                    })?
+                    .trim()
+                    .to_string()
+            }
+        };
+        Ok(Client::new(
+            &self.base_url,
+            session,
+            Duration::from_secs(self.interval),
+            std::env::temp_dir().join("aoc-last-request"),
+        ))
+    }
+}
+
+fn default_session_file() -> Option<PathBuf> {
+    let config = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config.join("aoc").join("session"))
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    /// Holds the time of the last request, so the interval is honoured across invocations.
+    throttle_file: PathBuf,
+}
+
+impl Client {
+    pub fn new(
+        base_url: &str,
+        session: String,
+        min_interval: Duration,
+        throttle_file: PathBuf,
+    ) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            min_interval,
+            throttle_file,
+        }
+    }
+
+    /// Downloads the puzzle input of `day` of `year`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.throttle();
+        let request = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+        Self::body(day, &url, request)
+    }
+
+    /// Posts `answer` for a part of `day` of `year`, returning the page the website answered with.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        self.throttle();
+        let request = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())]);
+        Self::body(day, &url, request)
+    }
+
+    fn body(
+        day: u8,
+        url: &str,
+        request: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> Result<String, String> {
+        let mut response = request.map_err(|err| format!("request to {url} failed: {err}"))?;
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("failed to read the response from {url}: {err}"))?;
+
+        match response.status().as_u16() {
+            200 => Ok(body),
+            404 => Err(format!("day {day} is not unlocked yet")),
+            400 | 500 => Err("the session token was rejected, it may have expired".into()),
+            status => Err(format!("{url} answered {status}: {}", body.trim())),
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then records this one.
+    fn throttle(&self) {
+        let last = fs::read_to_string(&self.throttle_file)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(wait) = last.and_then(|last| {
+            (last + self.min_interval)
+                .duration_since(SystemTime::now())
+                .ok()
+        }) {
+            info!("waiting {:.1}s before the next request", wait.as_secs_f64());
+            thread::sleep(wait);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock is after 1970");
+        // Failing to record the time only costs politeness on the next run, not this request.
+        let _ = fs::write(&self.throttle_file, now.as_millis().to_string());
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already on disk, so no request was made.
+    Cached(PathBuf),
+}
+
+/// Saves the input of `day` of `year` to `yearYYYY/dayN/input` under `dir`, unless it's already
+/// there.
+pub fn fetch(client: &Client, dir: &Path, year: u16, day: u8) -> Result<Fetched, String> {
+    let path = input::input_path(dir, year, day, &InputType::Actual);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+    let parent = path
+        .parent()
+        .expect("input paths are inside a day directory");
+    fs::create_dir_all(parent)
+        .map_err(|err| format!("failed to create {}: {err}", parent.display()))?;
+    fs::write(&path, input).map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn client(server: &MockServer, dir: &Path, min_interval: Duration) -> Client {
+        Client::new(
+            &server.base_url,
+            "abc123".into(),
+            min_interval,
+            dir.join("last-request"),
+        )
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fetch_downloads_with_session_cookie() {
+        let server = MockServer::start(vec![(200, "1 2 3\n")]);
+        let dir = temp_dir("download");
+        let client = client(&server, &dir, Duration::ZERO);
+
+        let fetched = fetch(&client, &dir, 2025, 3).unwrap();
+
+        let path = dir.join("year2025").join("day3").join("input");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(path).unwrap(), "1 2 3\n");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/3/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn fetch_never_downloads_cached_input() {
+        let server = MockServer::start(vec![(200, "fresh\n")]);
+        let dir = temp_dir("cached");
+        let client = client(&server, &dir, Duration::ZERO);
+
+        fetch(&client, &dir, 2025, 1).unwrap();
+        let fetched = fetch(&client, &dir, 2025, 1).unwrap();
+
+        let path = dir.join("year2025").join("day1").join("input");
+        assert_eq!(fetched, Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(path).unwrap(), "fresh\n");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn fetch_reports_locked_day_without_saving() {
+        let server = MockServer::start(vec![(404, "Not Found")]);
+        let dir = temp_dir("locked");
+        let client = client(&server, &dir, Duration::ZERO);
+
+        let err = fetch(&client, &dir, 2024, 12).unwrap_err();
+
+        assert_eq!(err, "day 12 is not unlocked yet");
+        assert!(!dir.join("year2024").join("day12").exists());
+        assert_eq!(server.requests()[0].path, "/2024/day/12/input");
+    }
+
+    #[test]
+    fn requests_honour_minimum_interval() {
+        let server = MockServer::start(vec![(200, "a"), (200, "b")]);
+        let dir = temp_dir("interval");
+        let client = client(&server, &dir, Duration::from_millis(300));
+
+        let start = Instant::now();
+        client.input(2025, 1).unwrap();
+        client.input(2025, 2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
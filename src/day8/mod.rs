@@ -1,24 +0,0 @@
-use crate::{DayImpl, InputType};
-
-pub struct Day {
-    input_type: InputType,
-}
-
-const INPUT: &str = include_str!("input");
-const EX_INPUT: &str = include_str!("example_input");
-
-impl Day {
-    pub fn new(input_type: InputType) -> Self {
-        Self { input_type }
-    }
-}
-
-impl DayImpl for Day {
-    fn part_one(&self) -> String {
-        todo!()
-    }
-
-    fn part_two(&self) -> String {
-        todo!()
-    }
-}
\ No newline at end of file
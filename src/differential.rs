@@ -0,0 +1,189 @@
+//! Differential testing: solving generated inputs with both a day's solver and a slow but
+//! obviously correct reference implementation, and shrinking any disagreement to a minimal
+//! counterexample.
+
+use std::{fmt::Display, ops::Range};
+
+use crate::{
+    Part,
+    answer::Answer,
+    generate::Rng,
+    registry::Registration,
+    runner::{Outcome, catch_panic, solve_part},
+};
+
+/// Straightforward solutions of a day, registered with `register_day!`. They take the raw input
+/// and parse it themselves, so the solver's parser is checked too.
+#[derive(Debug, Clone, Copy)]
+pub struct Reference {
+    pub part_one: fn(&str) -> Answer,
+    pub part_two: fn(&str) -> Answer,
+}
+
+impl Reference {
+    pub fn solve(&self, input: &str, part: Part) -> Answer {
+        match part {
+            Part::One => (self.part_one)(input),
+            Part::Two => (self.part_two)(input),
+        }
+    }
+}
+
+/// An input on which a solver and its reference disagree.
+#[derive(Debug)]
+pub struct Counterexample {
+    pub day: u8,
+    pub part: Part,
+    /// Seed of the generated input that first disagreed.
+    pub seed: u64,
+    /// The generated input, shrunk as far as it would go.
+    pub input: String,
+    pub expected: Answer,
+    pub actual: Outcome,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {} part {}: expected {}, got {} (seed {}, shrunk to {} lines)",
+            self.day,
+            self.part.number(),
+            self.expected,
+            self.actual,
+            self.seed,
+            self.input.lines().count()
+        )?;
+        for line in self.input.lines() {
+            writeln!(f, "  {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Solves the generated inputs for `seeds` with both the solver and the reference of `day`,
+/// returning the first disagreement, shrunk. Days without a generator or reference pass.
+pub fn check(
+    day: &Registration,
+    part: Part,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<(), Box<Counterexample>> {
+    let (Some(generator), Some(reference)) = (day.generator, day.reference) else {
+        return Ok(());
+    };
+    for seed in seeds {
+        let input = (generator.generate)(&mut Rng::new(seed), size);
+        if disagreement(day, &reference, part, &input).is_none() {
+            continue;
+        }
+
+        let input = shrink(&input, |candidate| {
+            disagreement(day, &reference, part, candidate).is_some()
+        });
+        let (expected, actual) =
+            disagreement(day, &reference, part, &input).expect("shrinking keeps the disagreement");
+        return Err(Box::new(Counterexample {
+            day: day.number,
+            part,
+            seed,
+            input,
+            expected,
+            actual,
+        }));
+    }
+    Ok(())
+}
+
+/// The reference answer and the solver's outcome, if they differ. Inputs the reference can't
+/// solve don't count, since they are outside what it understands.
+fn disagreement(
+    day: &Registration,
+    reference: &Reference,
+    part: Part,
+    input: &str,
+) -> Option<(Answer, Outcome)> {
+    let expected = catch_panic(|| reference.solve(input, part)).ok()?;
+    let solver = day.solver();
+    let actual = match catch_panic(|| solver.parse(input)) {
+        Ok(Ok(parsed)) => solve_part(solver.as_ref(), parsed.as_ref(), part),
+        Ok(Err(err)) => Outcome::InvalidInput(err.with_day(day.number)),
+        Err(message) => Outcome::Panicked(message),
+    };
+    match &actual {
+        Outcome::Answer(answer) if *answer == expected => None,
+        _ => Some((expected, actual)),
+    }
+}
+
+/// Removes as much of `input` as it can while `fails` still holds: first whole lines (or
+/// comma-separated items, for single-line inputs), then characters within the remaining lines.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let (body, terminator) = match input.strip_suffix('\n') {
+        Some(body) => (body, "\n"),
+        None => (input, ""),
+    };
+    let separator = if body.contains('\n') { "\n" } else { "," };
+    let items: Vec<String> = body.split(separator).map(String::from).collect();
+    let join = |items: &[String]| items.join(separator) + terminator;
+
+    let mut items = shrink_items(items, |items| fails(&join(items)));
+    for i in 0..items.len() {
+        let chars: Vec<String> = items[i].chars().map(String::from).collect();
+        let shrunk = shrink_items(chars, |chars| {
+            let mut candidate = items.clone();
+            candidate[i] = chars.concat();
+            fails(&join(&candidate))
+        });
+        items[i] = shrunk.concat();
+    }
+    join(&items)
+}
+
+/// Removes chunks of `items`, halving the chunk size down to single items, keeping every
+/// removal after which `fails` still holds.
+fn shrink_items(mut items: Vec<String>, fails: impl Fn(&[String]) -> bool) -> Vec<String> {
+    let mut chunk = items.len().div_ceil(2).max(1);
+    loop {
+        let mut start = 0;
+        while start < items.len() && items.len() > 1 {
+            let end = (start + chunk).min(items.len());
+            let candidate: Vec<String> = [&items[..start], &items[end..]].concat();
+            if !candidate.is_empty() && fails(&candidate) {
+                items = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+        if chunk == 1 {
+            return items;
+        }
+        chunk = chunk.div_ceil(2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_lines_then_characters() {
+        let input = "abc\ndef\nxyz\nghi\n";
+        let shrunk = shrink(input, |input| input.contains('y'));
+        assert_eq!(shrunk, "y\n");
+    }
+
+    #[test]
+    fn shrinks_items_of_single_line_inputs() {
+        let input = "1-2,3-4,50-60,7-8\n";
+        let shrunk = shrink(input, |input| input.contains("50"));
+        assert_eq!(shrunk, "50\n");
+    }
+
+    #[test]
+    fn keeps_input_that_needs_every_line() {
+        let input = "a\nb\n";
+        let shrunk = shrink(input, |input| input.contains('a') && input.contains('b'));
+        assert_eq!(shrunk, input);
+    }
+}
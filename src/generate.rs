@@ -0,0 +1,51 @@
+//! Synthetic puzzle inputs, for stressing the solutions without sharing real inputs.
+
+use std::ops::RangeInclusive;
+
+/// A day's input generator, registered with `register_day!`.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// Size of a typical puzzle input, in the generator's own unit (lines, grid side, ...).
+    pub default_size: usize,
+    /// Produces a valid input of roughly the given size. The same seed and size always produce
+    /// the same input.
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// SplitMix64, small and good enough for test data, and stable across platforms and releases
+/// so a seed always reproduces the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, inclusive.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// A number with exactly `digits` digits, without a leading zero.
+    pub fn digits(&mut self, digits: u32) -> u64 {
+        self.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+    }
+}
@@ -0,0 +1,656 @@
+//! Graphs and the usual algorithms over them, for the path-finding and connectivity days.
+//!
+//! Algorithms work on anything implementing [`Graph`]: an [`AdjacencyList`] built from the input,
+//! or an [`Implicit`] graph whose edges are computed on demand, like the open neighbours of a
+//! point in a [`Grid`](crate::grid::Grid). Nodes can be any hashable value, such as a device name
+//! or a [`Point`](crate::grid::Point). Nothing recurses, so long paths can't overflow the stack.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque, hash_map::Entry},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use crate::memo::Memo;
+
+/// Nodes and the weighted, directed edges leaving them.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The edges leaving `node`, as the node each leads to and its weight.
+    fn edges(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+
+    /// The nodes `node` has an edge to.
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node> {
+        self.edges(node).map(|(neighbour, _)| neighbour)
+    }
+}
+
+/// A graph stored as a list of edges for every node, the nodes numbered in the order they were
+/// added.
+#[derive(Debug, Clone)]
+pub struct AdjacencyList<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl<N> Default for AdjacencyList<N> {
+    fn default() -> Self {
+        AdjacencyList {
+            nodes: vec![],
+            index: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> AdjacencyList<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` without any edges, unless it's already there. Returns its number.
+    pub fn add_node(&mut self, node: N) -> usize {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                self.nodes.push(entry.key().clone());
+                self.edges.push(vec![]);
+                *entry.insert(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    /// Adds an edge of weight 1 from `from` to `to`, adding the nodes as needed.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, from: N, to: N, weight: u64) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    /// Adds edges of weight 1 both ways between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    /// Every node, in the order they were added.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph for AdjacencyList<N> {
+    type Node = N;
+
+    fn edges(&self, node: &N) -> impl Iterator<Item = (N, u64)> {
+        let edges = match self.index.get(node) {
+            Some(&i) => self.edges[i].as_slice(),
+            None => &[],
+        };
+        edges
+            .iter()
+            .map(|&(to, weight)| (self.nodes[to].clone(), weight))
+    }
+}
+
+/// Unweighted edges `from -> to`, e.g. `[("you", "bbb"), ("bbb", "out")]`.
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for AdjacencyList<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        let mut graph = AdjacencyList::new();
+        for (from, to) in iter {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+/// A graph whose edges come from a function, for graphs too big or too regular to store.
+pub struct Implicit<N, F> {
+    edges: F,
+    node: PhantomData<fn(&N)>,
+}
+
+impl<N, F, I> Implicit<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    /// The graph where `edges(node)` gives the edges leaving `node`, with their weights.
+    pub fn new(edges: F) -> Self {
+        Implicit {
+            edges,
+            node: PhantomData,
+        }
+    }
+}
+
+/// The graph where `neighbours(node)` gives the nodes `node` has an edge of weight 1 to.
+pub fn unweighted<N, I>(neighbours: impl Fn(&N) -> I) -> impl Graph<Node = N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    Implicit::new(move |node: &N| neighbours(node).into_iter().map(|neighbour| (neighbour, 1)))
+}
+
+impl<N, F, I> Graph for Implicit<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    type Node = N;
+
+    fn edges(&self, node: &N) -> impl Iterator<Item = (N, u64)> {
+        (self.edges)(node).into_iter()
+    }
+}
+
+/// The shortest distances from a start node to the nodes reached, and the paths taking them.
+#[derive(Debug, Clone)]
+pub struct Paths<N> {
+    distances: HashMap<N, u64>,
+    previous: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new(start: N) -> Self {
+        Paths {
+            distances: HashMap::from([(start, 0)]),
+            previous: HashMap::new(),
+        }
+    }
+
+    /// The distance to `node`, `None` if it wasn't reached.
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// A shortest path from the start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.previous.get(path.last().expect("path isn't empty")) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node reached, with its distance.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, u64)> {
+        self.distances
+            .iter()
+            .map(|(node, distance)| (node, *distance))
+    }
+}
+
+/// The nodes reachable from `start`, counting the edges to each and ignoring their weights.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> Paths<G::Node> {
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node];
+        for neighbour in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = paths.distances.entry(neighbour.clone()) {
+                entry.insert(distance + 1);
+                paths.previous.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    paths
+}
+
+/// The nodes reachable from `start`, depth first, each before the nodes found through it.
+pub fn dfs<G: Graph>(graph: &G, start: G::Node) -> Vec<G::Node> {
+    let mut visited = HashSet::new();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        // Reversed, so the first neighbour is visited first.
+        let neighbours: Vec<_> = graph.neighbours(&node).collect();
+        stack.extend(neighbours.into_iter().rev());
+        order.push(node);
+    }
+    order
+}
+
+/// The shortest weighted distances from `start` to every node reachable from it.
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node) -> Paths<G::Node> {
+    let mut paths = Paths::new(start.clone());
+    search(graph, start, |_| false, |_| 0, &mut paths);
+    paths
+}
+
+/// A shortest path from `start` to a node matching `is_goal`, with its length. `heuristic` must
+/// never overestimate the distance left to a goal, nor drop by more than an edge's weight across
+/// it, as distances like Manhattan's on a grid do. With `|_| 0` this is Dijkstra's algorithm
+/// stopping at the first goal.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Option<(u64, Vec<G::Node>)> {
+    let mut paths = Paths::new(start.clone());
+    let goal = search(graph, start, is_goal, heuristic, &mut paths)?;
+    Some((paths.distances[&goal], paths.path_to(&goal)?))
+}
+
+/// A* from `start`, filling in `paths` until a goal is settled, which is returned.
+fn search<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+    paths: &mut Paths<G::Node>,
+) -> Option<G::Node> {
+    // Nodes needn't be `Ord`, so the heap orders their positions in `queued`.
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+    let mut settled = HashSet::new();
+    heap.push(Reverse((heuristic(&start), 0, 0)));
+    queued.push(start);
+
+    while let Some(Reverse((_, distance, i))) = heap.pop() {
+        let node = queued[i].clone();
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(node);
+        }
+        for (neighbour, weight) in graph.edges(&node) {
+            let distance = distance + weight;
+            if paths
+                .distances
+                .get(&neighbour)
+                .is_some_and(|known| *known <= distance)
+            {
+                continue;
+            }
+            paths.distances.insert(neighbour.clone(), distance);
+            paths.previous.insert(neighbour.clone(), node.clone());
+            heap.push(Reverse((
+                distance + heuristic(&neighbour),
+                distance,
+                queued.len(),
+            )));
+            queued.push(neighbour);
+        }
+    }
+    None
+}
+
+/// Every node reachable from `roots`, each before all the nodes it has an edge to, or `Err` with
+/// a node on a cycle.
+pub fn toposort<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Result<Vec<G::Node>, G::Node> {
+    // Nodes are finished once everything reachable from them is, building the order backwards.
+    let mut finished = HashSet::new();
+    let mut in_progress = HashSet::new();
+    let mut order = vec![];
+    for root in roots {
+        if finished.contains(&root) {
+            continue;
+        }
+        in_progress.insert(root.clone());
+        let mut stack = vec![(root.clone(), graph.neighbours(&root).collect::<Vec<_>>())];
+        while let Some((node, neighbours)) = stack.last_mut() {
+            match neighbours.pop() {
+                Some(next) if in_progress.contains(&next) => return Err(next),
+                Some(next) if finished.contains(&next) => {}
+                Some(next) => {
+                    in_progress.insert(next.clone());
+                    let next_neighbours = graph.neighbours(&next).collect();
+                    stack.push((next, next_neighbours));
+                }
+                None => {
+                    in_progress.remove(node);
+                    finished.insert(node.clone());
+                    order.push(node.clone());
+                    stack.pop();
+                }
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// Number of distinct paths from `from` to `to` in a graph without cycles. Panics on a cycle
+/// reachable from `from`.
+pub fn count_paths<G: Graph>(graph: &G, from: G::Node, to: &G::Node) -> u64 {
+    Memo::new().solve(from, |node, paths| {
+        if node == to {
+            return Some(1);
+        }
+        // Looks every neighbour up before giving up, so all the missing ones are queued at once.
+        let counts: Vec<Option<u64>> = graph
+            .neighbours(node)
+            .map(|neighbour| paths.get(neighbour))
+            .collect();
+        counts.into_iter().sum()
+    })
+}
+
+/// The strongly connected components of the nodes reachable from `roots`: the largest sets of
+/// nodes that can all reach each other. Components come out in reverse topological order, each
+/// after every component it has an edge to.
+pub fn strongly_connected_components<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    // Tarjan's algorithm, with the recursion kept on `calls`.
+    struct Visit {
+        index: usize,
+        low_link: usize,
+        on_stack: bool,
+    }
+    let mut visits: HashMap<G::Node, Visit> = HashMap::new();
+    let mut stack = vec![];
+    let mut components = vec![];
+
+    // Visits `node`, returning the call to push: the node and the neighbours left to look at.
+    let start = |node: G::Node, visits: &mut HashMap<G::Node, Visit>, stack: &mut Vec<G::Node>| {
+        let index = visits.len();
+        visits.insert(
+            node.clone(),
+            Visit {
+                index,
+                low_link: index,
+                on_stack: true,
+            },
+        );
+        stack.push(node.clone());
+        let neighbours: Vec<G::Node> = graph.neighbours(&node).collect();
+        (node, neighbours)
+    };
+
+    for root in roots {
+        if visits.contains_key(&root) {
+            continue;
+        }
+        let mut calls = vec![start(root, &mut visits, &mut stack)];
+        while let Some((node, neighbours)) = calls.last_mut() {
+            if let Some(next) = neighbours.pop() {
+                match visits.get(&next) {
+                    None => {
+                        let call = start(next, &mut visits, &mut stack);
+                        calls.push(call);
+                    }
+                    Some(visit) if visit.on_stack => {
+                        let index = visit.index;
+                        let visit = visits.get_mut(node).expect("node is visited");
+                        visit.low_link = visit.low_link.min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            let (node, _) = calls.pop().expect("a call is in progress");
+            let Visit {
+                index, low_link, ..
+            } = visits[&node];
+            if low_link == index {
+                let mut component = vec![];
+                loop {
+                    let member = stack.pop().expect("the node is on the stack");
+                    visits.get_mut(&member).expect("member is visited").on_stack = false;
+                    let done = member == node;
+                    component.push(member);
+                    if done {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some((parent, _)) = calls.last() {
+                let parent = visits.get_mut(parent).expect("parent is visited");
+                parent.low_link = parent.low_link.min(low_link);
+            }
+        }
+    }
+    components
+}
+
+/// Disjoint sets of nodes, merged with [`UnionFind::union`], tracking the size of each set.
+#[derive(Debug, Clone)]
+pub struct UnionFind<N> {
+    index: HashMap<N, usize>,
+    parent: Vec<usize>,
+    /// The size of the set, for the root of each set.
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl<N> Default for UnionFind<N> {
+    fn default() -> Self {
+        UnionFind {
+            index: HashMap::new(),
+            parent: vec![],
+            size: vec![],
+            sets: 0,
+        }
+    }
+}
+
+impl<N: Eq + Hash> UnionFind<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` in a set of its own, unless it's already there.
+    pub fn insert(&mut self, node: N) -> usize {
+        let next = self.parent.len();
+        let i = *self.index.entry(node).or_insert(next);
+        if i == next {
+            self.parent.push(i);
+            self.size.push(1);
+            self.sets += 1;
+        }
+        i
+    }
+
+    /// The root of the set holding the node numbered `i`, shortening the path to it on the way.
+    fn root(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Merges the sets of `a` and `b`, adding them as needed. Returns whether they were apart.
+    pub fn union(&mut self, a: N, b: N) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        let (a, b) = (self.root(a), self.root(b));
+        if a == b {
+            return false;
+        }
+        // The smaller set goes under the larger, keeping paths short.
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.sets -= 1;
+        true
+    }
+
+    /// Whether `a` and `b` are in the same set. Nodes never added are only with themselves.
+    pub fn connected(&mut self, a: &N, b: &N) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.root(a) == self.root(b),
+            _ => a == b,
+        }
+    }
+
+    /// Size of the set holding `node`, 0 if it was never added.
+    pub fn size(&mut self, node: &N) -> usize {
+        match self.index.get(node) {
+            Some(&i) => {
+                let root = self.root(i);
+                self.size[root]
+            }
+            None => 0,
+        }
+    }
+
+    /// Sizes of every set, largest first.
+    pub fn set_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.parent.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Number of sets.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Point};
+
+    fn devices() -> AdjacencyList<&'static str> {
+        [
+            ("you", "bbb"),
+            ("you", "ccc"),
+            ("bbb", "ddd"),
+            ("bbb", "eee"),
+            ("ccc", "ddd"),
+            ("ccc", "eee"),
+            ("ccc", "fff"),
+            ("ddd", "ggg"),
+            ("eee", "out"),
+            ("fff", "out"),
+            ("ggg", "out"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn labelled_graph_searches() {
+        let graph = devices();
+        assert_eq!(count_paths(&graph, "you", &"out"), 5);
+        assert_eq!(count_paths(&graph, "ddd", &"eee"), 0);
+
+        let order = toposort(&graph, ["you"]).unwrap();
+        assert_eq!(order.len(), graph.len());
+        let position = |node: &str| order.iter().position(|n| *n == node).unwrap();
+        for from in graph.nodes() {
+            for to in graph.neighbours(from) {
+                assert!(position(from) < position(to));
+            }
+        }
+
+        let paths = bfs(&graph, "you");
+        assert_eq!(paths.distance(&"out"), Some(3));
+        assert_eq!(paths.path_to(&"ggg").unwrap(), ["you", "bbb", "ddd", "ggg"]);
+        assert_eq!(dfs(&graph, "you")[..4], ["you", "bbb", "ddd", "ggg"]);
+    }
+
+    #[test]
+    fn weighted_shortest_paths() {
+        let mut graph = AdjacencyList::new();
+        graph.add_weighted_edge('a', 'b', 7);
+        graph.add_weighted_edge('a', 'c', 2);
+        graph.add_weighted_edge('c', 'b', 3);
+        graph.add_weighted_edge('b', 'd', 1);
+        let paths = dijkstra(&graph, 'a');
+        assert_eq!(paths.distance(&'d'), Some(6));
+        assert_eq!(paths.path_to(&'d').unwrap(), ['a', 'c', 'b', 'd']);
+        assert_eq!(paths.distance(&'e'), None);
+        assert_eq!(
+            astar(&graph, 'a', |n| *n == 'b', |_| 0),
+            Some((5, vec!['a', 'c', 'b']))
+        );
+    }
+
+    #[test]
+    fn implicit_grid_graph() {
+        let grid = Grid::parse_with("S.#\n#.#\n..E\n", Ok).unwrap();
+        let open = unweighted(|point: &Point| grid.neighbours4(*point).filter(|p| grid[*p] != '#'));
+        let (start, end) = (Point::new(0, 0), Point::new(2, 2));
+        let manhattan = |p: &Point| (p.x.abs_diff(end.x) + p.y.abs_diff(end.y)) as u64;
+        let (length, path) = astar(&open, start, |p| *p == end, manhattan).unwrap();
+        assert_eq!(length, 4);
+        assert_eq!(path.len(), 5);
+        assert_eq!(bfs(&open, start).reached().count(), 6);
+        assert_eq!(
+            astar(&open, start, |p| p.x == 2 && p.y == 0, manhattan),
+            None
+        );
+    }
+
+    #[test]
+    fn cycles_and_components() {
+        let graph: AdjacencyList<u8> = [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4)]
+            .into_iter()
+            .collect();
+        assert!(toposort(&graph, [1]).is_err());
+
+        let mut components = strongly_connected_components(&graph, [1]);
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(components, [vec![4, 5], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn union_find_tracks_sizes() {
+        let mut sets = UnionFind::new();
+        assert!(sets.union("a", "b"));
+        assert!(sets.union("c", "d"));
+        assert!(sets.union("b", "d"));
+        assert!(!sets.union("a", "c"));
+        sets.insert("e");
+        assert!(sets.connected(&"a", &"d"));
+        assert!(!sets.connected(&"a", &"e"));
+        assert_eq!(sets.size(&"c"), 4);
+        assert_eq!(sets.set_sizes(), [4, 1]);
+        assert_eq!((sets.sets(), sets.len()), (2, 5));
+    }
+}
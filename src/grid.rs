@@ -0,0 +1,364 @@
+//! Rectangular grids of cells, for the days whose input is a character map.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{ParseError, Span};
+
+/// A position in a [`Grid`], `x` counting columns from the left and `y` rows from the top.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    /// The point `dx` columns and `dy` rows away, unless that's left of or above the grid.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Offsets of the orthogonal neighbours: up, right, down, left.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise from up.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Cells stored row by row in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// The grid holding `cells` row by row, `width` to a row. Panics unless the cells fill whole
+    /// rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or_default();
+        assert_eq!(width * height, cells.len(), "cells don't fill whole rows");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map with one row per line, converting each character with
+    /// `T::try_from`. Blank lines around the map and spaces around each row are ignored, but every
+    /// row must be as wide as the first.
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        Self::parse_with(input, |c| T::try_from(c).map_err(|err| err.to_string()))
+    }
+
+    /// Like [`Grid::parse`], converting each character with `cell`.
+    pub fn parse_with(
+        input: &str,
+        cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_span(Span::new(input), cell)
+    }
+
+    /// Like [`Grid::parse_with`], for a part of a larger input, see [`Span::grid`].
+    pub fn parse_span(
+        span: Span,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<Span> = span.trim().lines().map(|line| line.trim()).collect();
+        let Some(width) = lines.first().map(|line| line.as_str().chars().count()) else {
+            return Err(span.error("Empty grid"));
+        };
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            let line_width = line.as_str().chars().count();
+            if line_width != width {
+                return Err(line.error(format!(
+                    "Row is {line_width} wide, expected {width} like the first row"
+                )));
+            }
+            for (c, at) in line.chars() {
+                cells.push(cell(c).map_err(|message| at.error(message))?);
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The points `offsets` away from `point` that are inside the grid.
+    fn neighbours(
+        &self,
+        point: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| point.offset(*dx, *dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The orthogonal neighbours of `point` inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &NEIGHBOURS8)
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom. Panics if `x` is outside the grid, like
+    /// [`Grid::row`].
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside the {}x{} grid",
+            self.width,
+            self.height
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Every point whose cell matches `predicate`, row by row.
+    pub fn find_all(&self, predicate: impl Fn(&T) -> bool) -> impl Iterator<Item = Point> {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid one line per row, drawing each cell as the character `to_char` maps it to.
+    pub fn display_with(&self, to_char: impl Fn(&T) -> char) -> impl Display {
+        DisplayWith {
+            grid: self,
+            to_char,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// Draws the grid with each cell as the character it converts into, the reverse of
+/// [`Grid::parse`].
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(|cell| (*cell).into()).fmt(f)
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    to_char: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            let line: String = row.iter().map(&self.to_char).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse_with("ab.\n.c#\n\n", Ok).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], '#');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), ['.', 'c', '#']);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["a.", "bc", ".#"]);
+        assert_eq!(grid.to_string(), "ab.\n.c#\n");
+    }
+
+    #[test]
+    fn points_at_invalid_cells_and_ragged_rows() {
+        let err = Grid::parse_with("ab\naX\n", |c| match c {
+            'X' => Err("Invalid cell".into()),
+            c => Ok(c),
+        })
+        .unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 2, "Invalid cell")
+        );
+
+        let err = Grid::parse_with("ab\nabc\n", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Grid::parse_with("\n", Ok).is_err());
+    }
+
+    #[test]
+    fn ignores_blank_lines_and_spaces_around_rows() {
+        let padded = Grid::parse_with("\n  \nab. \n.c#\r\n \n", Ok).unwrap();
+        assert_eq!(padded, grid());
+
+        let err = Grid::parse_with("\nab\nabc\n", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 is outside the 2x2 grid")]
+    fn columns_past_the_edge_panic() {
+        let grid = Grid::parse_with("ab\ncd\n", Ok).unwrap();
+        let _ = grid.column(2);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = grid();
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = grid();
+        assert_eq!(grid.find(|c| *c == '.'), Some(Point::new(2, 0)));
+        let dots: Vec<Point> = grid.find_all(|c| *c == '.').collect();
+        assert_eq!(dots, [Point::new(2, 0), Point::new(0, 1)]);
+        assert_eq!(grid.find(|c| *c == 'z'), None);
+    }
+}
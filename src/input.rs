@@ -0,0 +1,112 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::InputType;
+
+/// Directory that holds the `yearYYYY/dayN/input` and `yearYYYY/dayN/example_input` files when
+/// no other location is given.
+pub const DEFAULT_INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Where the puzzle input for a day should be read from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// Embedded inputs when built with `embed-inputs`, otherwise the default inputs directory.
+    Default,
+    /// A directory laid out as `<dir>/yearYYYY/dayN/input` and `<dir>/yearYYYY/dayN/example_input`.
+    Directory(PathBuf),
+    /// A single file, used regardless of the day or input type.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn new(input: Option<PathBuf>, inputs_dir: Option<PathBuf>) -> Self {
+        match (input, inputs_dir) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(path),
+            (None, Some(dir)) => InputSource::Directory(dir),
+            (None, None) => InputSource::Default,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(
+                f,
+                "input file not found: {} (pass --input <path>, --input - for stdin, or --inputs-dir <dir>)",
+                path.display()
+            ),
+            InputError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Path of a day's input file inside an inputs directory.
+pub fn input_path(dir: &Path, year: u16, day: u8, input_type: &InputType) -> PathBuf {
+    let file_name = match input_type {
+        InputType::Actual => "input".to_string(),
+        InputType::Example(None) => "example_input".to_string(),
+        InputType::Example(Some(name)) => format!("example_input_{name}"),
+    };
+    dir.join(format!("year{year}"))
+        .join(format!("day{day}"))
+        .join(file_name)
+}
+
+pub fn load(
+    year: u16,
+    day: u8,
+    input_type: &InputType,
+    source: &InputSource,
+) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => {
+            #[cfg(feature = "embed-inputs")]
+            if let Some(input) = crate::registry::embedded(year, day, input_type) {
+                return Ok(input.into());
+            }
+            read_file(&input_path(
+                Path::new(DEFAULT_INPUTS_DIR),
+                year,
+                day,
+                input_type,
+            ))
+        }
+        InputSource::Directory(dir) => read_file(&input_path(dir, year, day, input_type)),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| InputError::Io("<stdin>".into(), err))?;
+            Ok(input)
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash of an input, to tell which input produced an answer without storing it.
+pub fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.into()),
+        _ => InputError::Io(path.into(), err),
+    })
+}
@@ -0,0 +1,259 @@
+//! Sets of integers stored as sorted, disjoint intervals, for the days about ranges of IDs.
+
+use std::{fmt::Display, ops::Range};
+
+/// The integers from `start` up to but not including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Interval {
+    /// The half-open interval `start..end`, empty when `end <= start`.
+    pub fn new(start: u64, end: u64) -> Self {
+        Interval {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    /// The closed interval `first..=last`, the way puzzles usually write ranges. `last` must be
+    /// below `u64::MAX`.
+    pub fn inclusive(first: u64, last: u64) -> Self {
+        Interval::new(first, last + 1)
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The largest value in the interval, `None` when it's empty.
+    pub fn last(&self) -> Option<u64> {
+        (!self.is_empty()).then(|| self.end - 1)
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Every value in the interval, in order.
+    pub fn values(&self) -> Range<u64> {
+        self.start..self.end
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch, so every gap
+/// between two intervals holds at least one value outside the set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // Intervals ending before `interval` starts, and those starting after it ends, stay.
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, existing| Interval {
+                start: merged.start.min(existing.start),
+                end: merged.end.max(existing.end),
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` is in the set, by binary search.
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals making up the set, sorted, without overlaps.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        // Both sides are sorted, so a merge-like walk visits each overlapping pair once.
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let overlap = a.intersection(b);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values of `self` that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let Some(bounds) = self.bounds() else {
+            return IntervalSet::new();
+        };
+        self.intersection(&other.complement(bounds))
+    }
+
+    /// The values of `within` that aren't in the set.
+    pub fn complement(&self, within: Interval) -> IntervalSet {
+        let mut intervals = vec![];
+        let mut start = within.start;
+        for interval in &self.intervals {
+            let gap = Interval::new(start, interval.start).intersection(&within);
+            if !gap.is_empty() {
+                intervals.push(gap);
+            }
+            start = start.max(interval.end);
+        }
+        let rest = Interval::new(start, within.end);
+        if !rest.is_empty() {
+            intervals.push(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The smallest interval holding the whole set, `None` when it's empty.
+    pub fn bounds(&self) -> Option<Interval> {
+        Some(Interval::new(
+            self.intervals.first()?.start,
+            self.intervals.last()?.end,
+        ))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(Interval::to_string).collect();
+        write!(f, "{{{}}}", intervals.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(u64, u64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn insert_coalesces_overlapping_and_touching_intervals() {
+        let mut intervals = set(&[(10, 15), (20, 25), (30, 35)]);
+        intervals.insert(Interval::new(15, 20));
+        assert_eq!(intervals, set(&[(10, 25), (30, 35)]));
+        intervals.insert(Interval::new(0, 40));
+        assert_eq!(intervals, set(&[(0, 40)]));
+        intervals.insert(Interval::new(50, 50));
+        assert_eq!(intervals.intervals().len(), 1);
+    }
+
+    #[test]
+    fn inclusive_ranges_overlapping_in_any_order() {
+        let intervals: IntervalSet = [(16, 20), (3, 5), (12, 18), (10, 14)]
+            .into_iter()
+            .map(|(first, last)| Interval::inclusive(first, last))
+            .collect();
+        assert_eq!(intervals, set(&[(3, 6), (10, 21)]));
+        assert_eq!(intervals.len(), 14);
+    }
+
+    #[test]
+    fn contains_checks_the_boundaries() {
+        let intervals = set(&[(3, 6), (10, 21)]);
+        let members: Vec<u64> = (0..25).filter(|n| intervals.contains(*n)).collect();
+        assert_eq!(
+            members,
+            [3, 4, 5, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+        );
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(
+            a.complement(Interval::new(5, 40)),
+            set(&[(10, 20), (30, 40)])
+        );
+        assert_eq!(
+            IntervalSet::new().complement(Interval::new(1, 2)),
+            set(&[(1, 2)])
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    InputType,
+    answer::Answer,
+    runner::{Outcome, Report},
+};
+
+/// Where the accepted answers of `year` are recorded when no other location is given.
+pub fn default_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("answers")
+        .join(format!("{year}.json"))
+}
+
+/// Accepted answers of one year, keyed by input type, then day, then part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger(BTreeMap<String, BTreeMap<u8, BTreeMap<u8, Answer>>>);
+
+impl Ledger {
+    /// Loads the ledger at `path`, or an empty one if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|err| format!("invalid answers file {}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).expect("ledger is serializable");
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+        }
+        std::fs::write(path, contents + "\n")
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, input_type: &InputType, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&input_type.to_string())?.get(&day)?.get(&part)
+    }
+
+    /// Records `answer`, returning the answer it replaced.
+    pub fn record(
+        &mut self,
+        input_type: &InputType,
+        day: u8,
+        part: u8,
+        answer: Answer,
+    ) -> Option<Answer> {
+        self.0
+            .entry(input_type.to_string())
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(part, answer)
+    }
+
+    /// Days with at least one recorded answer.
+    pub fn days(&self, input_type: &InputType) -> Vec<u8> {
+        self.0
+            .get(&input_type.to_string())
+            .map(|days| days.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Checks every result of `report` that has a recorded answer.
+    pub fn verify(&self, report: &Report, input_type: &InputType) -> Vec<Verification> {
+        report
+            .results()
+            .iter()
+            .filter_map(|result| {
+                let part = result.part.number();
+                let expected = self.get(input_type, result.day, part)?;
+                let verdict = match &result.outcome {
+                    Outcome::Answer(answer) if answer == expected => Verdict::Pass,
+                    Outcome::Answer(answer) => Verdict::Mismatch {
+                        expected: expected.clone(),
+                        actual: answer.clone(),
+                    },
+                    outcome => Verdict::Fail(outcome.to_string()),
+                };
+                Some(Verification {
+                    day: result.day,
+                    part,
+                    verdict,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// The part produced no answer at all, e.g. because it panicked or its input is missing.
+    Fail(String),
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match &self.verdict {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH (expected {expected}, got {actual})")
+            }
+            Verdict::Fail(reason) => write!(f, "FAIL ({})", reason.replace('\n', "\n    ")),
+        }
+    }
+}
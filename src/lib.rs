@@ -0,0 +1,150 @@
+//! Advent of Code solutions, and everything needed to run, check and submit them.
+//!
+//! Each year lives in its own `yearYYYY` module, and each of its days in a `dayN` module below
+//! it, exposing the day's domain types along with `parse`, `part_one` and `part_two` functions
+//! that take the puzzle input as a `&str`:
+//!
+//! ```
+//! let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+//! let answer = aoc2025::year2025::day1::part_one(input);
+//! assert_eq!(answer.unwrap(), aoc2025::Answer::from(3));
+//! ```
+
+use std::{any::Any, fmt::Display, str::FromStr};
+
+pub use answer::Answer;
+pub use parse::ParseError;
+
+// Declared first so its macros are in scope in every other module.
+#[macro_use]
+pub mod log;
+
+pub mod answer;
+pub mod bench;
+pub mod client;
+pub mod differential;
+pub mod generate;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod ledger;
+pub mod memo;
+#[cfg(test)]
+mod mock_server;
+pub mod output;
+pub mod parse;
+#[macro_use]
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod trace;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" | "One" => Ok(Part::One),
+            "2" | "two" | "Two" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {}", s)),
+        }
+    }
+}
+
+pub trait DayImpl {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+/// Object-safe view of a [`DayImpl`], so the runner can parse once and hand the parsed input to
+/// either part without knowing its type.
+pub trait Solver {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer;
+}
+
+impl<D: DayImpl> Solver for D
+where
+    D::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(D::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Answer {
+        let input = parsed
+            .downcast_ref::<D::Input>()
+            .expect("parsed input comes from the same day");
+        match part {
+            Part::One => D::part_one(input),
+            Part::Two => D::part_two(input),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum InputType {
+    #[default]
+    Actual,
+    /// The day's `example_input`, or `example_input_<name>` when named.
+    Example(Option<String>),
+}
+
+impl FromStr for InputType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None => match s {
+                "actual" | "a" => Ok(InputType::Actual),
+                "example" | "e" => Ok(InputType::Example(None)),
+                _ => Err(format!("Invalid input type: {}", s)),
+            },
+            Some(("example" | "e", name))
+                if !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+            {
+                Ok(InputType::Example(Some(name.into())))
+            }
+            Some(_) => Err(format!(
+                "Invalid input type: {} (examples are named with letters, digits, '_' and '-', e.g. example:2)",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for InputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputType::Actual => write!(f, "actual"),
+            InputType::Example(None) => write!(f, "example"),
+            InputType::Example(Some(name)) => write!(f, "example:{name}"),
+        }
+    }
+}
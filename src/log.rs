@@ -0,0 +1,273 @@
+//! Leveled diagnostics on stderr, filtered per module so `--log day7=debug` shows one day's
+//! debugging without the rest. Solutions log with [`error!`], [`warn!`], [`info!`], [`debug!`] and
+//! [`trace!`] instead of printing, keeping stdout to the answers. A disabled message costs one
+//! atomic load: its arguments aren't even formatted.
+
+use std::{
+    fmt::{self, Display},
+    io::Write,
+    str::FromStr,
+    sync::{
+        RwLock,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level shown by default, raised by each `-v`.
+    pub const DEFAULT: Level = Level::Warn;
+
+    /// The level shown with `verbosity` times `-v`.
+    pub fn from_verbosity(verbosity: u8) -> Level {
+        match verbosity {
+            0 => Level::DEFAULT,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "Invalid log level: {s} (expected error, warn, info, debug or trace)"
+            )),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Warn => write!(f, "warn"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+/// Which messages to show: a default level, overridden for some modules. Parsed from a
+/// comma-separated list of `level` and `module=level`, e.g. `info,day7=debug`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Level,
+    /// Module paths below the crate, like `day7` or `runner`, and the level shown for each.
+    pub modules: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            default: Level::DEFAULT,
+            modules: vec![],
+        }
+    }
+}
+
+impl Filter {
+    /// The level shown for `target`, from the most specific module matching it. Day modules
+    /// match with or without their year, so `day7` covers `year2025::day7` too.
+    pub fn level(&self, target: &str) -> Level {
+        let within_year = target
+            .split_once("::")
+            .filter(|(year, _)| is_year_module(year))
+            .map(|(_, rest)| rest);
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                is_within(target, module) || within_year.is_some_and(|rest| is_within(rest, module))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// The filter with its default raised to the level of `verbosity` times `-v`. Without any
+    /// `-v` the default stays as given, so it can be quieter than [`Level::DEFAULT`].
+    pub fn with_verbosity(mut self, verbosity: u8) -> Filter {
+        if verbosity > 0 {
+            self.default = self.default.max(Level::from_verbosity(verbosity));
+        }
+        self
+    }
+
+    /// The most verbose level shown for any module.
+    fn max_level(&self) -> Level {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+/// Whether `target` is `module` or one of its submodules.
+fn is_within(target: &str, module: &str) -> bool {
+    target
+        .strip_prefix(module)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+/// Whether `module` is a year module like `year2025`, generated by `build.rs`.
+fn is_year_module(module: &str) -> bool {
+    module
+        .strip_prefix("year")
+        .is_some_and(|year| !year.is_empty() && year.bytes().all(|b| b.is_ascii_digit()))
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => filter.modules.push((module.into(), level.parse()?)),
+                None => filter.default = directive.parse()?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// [`Filter::max_level`] of the installed filter, as a `Level` discriminant.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::DEFAULT as u8);
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+
+/// Replaces the filter deciding which messages are shown.
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = Some(filter);
+}
+
+/// `module_path` without the crate name, e.g. `year2025::day7` for `aoc2025::year2025::day7`.
+pub fn target(module_path: &str) -> &str {
+    module_path
+        .split_once("::")
+        .map_or(module_path, |(_, target)| target)
+}
+
+/// Whether a message at `level` from `module_path` is shown. Checks the cheap upper bound first,
+/// so disabled messages don't touch the filter.
+#[inline]
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed) && {
+        let target = target(module_path);
+        match FILTER
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .as_ref()
+        {
+            Some(filter) => level <= filter.level(target),
+            None => level <= Level::DEFAULT,
+        }
+    }
+}
+
+/// Writes a message that passed [`enabled`]. Use the macros instead.
+pub fn write(level: Level, module_path: &str, message: fmt::Arguments) {
+    // Logging must never fail the solution, so a closed stderr just loses the message.
+    let _ = writeln!(
+        std::io::stderr().lock(),
+        "[{level} {}] {message}",
+        target(module_path)
+    );
+}
+
+/// Logs a message at the given [`Level`], formatted like `format!`.
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[allow(unused_macros, reason = "nothing logs at this level yet")]
+macro_rules! error {
+    ($($arg:tt)+) => { log!($crate::log::Level::Error, $($arg)+) };
+}
+
+macro_rules! warn {
+    ($($arg:tt)+) => { log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+macro_rules! info {
+    ($($arg:tt)+) => { log!($crate::log::Level::Info, $($arg)+) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => { log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[allow(unused_macros, reason = "nothing logs at this level yet")]
+macro_rules! trace {
+    ($($arg:tt)+) => { log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_filters() {
+        let filter: Filter = "info, day7=debug,day7::grid=trace".parse().unwrap();
+        assert_eq!(filter.default, Level::Info);
+        assert_eq!(filter.level("day7"), Level::Debug);
+        assert_eq!(filter.level("day7::grid"), Level::Trace);
+        assert_eq!(filter.level("day7::grid::cell"), Level::Trace);
+        assert_eq!(filter.level("day70"), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+        assert!("day7=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn verbosity_only_raises_the_default() {
+        let quiet: Filter = "error".parse().unwrap();
+        assert_eq!(quiet.clone().with_verbosity(0).default, Level::Error);
+        assert_eq!(quiet.with_verbosity(2).default, Level::Debug);
+
+        let filter: Filter = "trace,day7=warn".parse().unwrap();
+        let filter = filter.with_verbosity(1);
+        assert_eq!(filter.default, Level::Trace);
+        assert_eq!(filter.level("day7"), Level::Warn);
+    }
+
+    #[test]
+    fn day_modules_match_with_or_without_their_year() {
+        let filter: Filter = "day5=debug,year2024::day7=trace".parse().unwrap();
+        assert_eq!(filter.level("year2025::day5"), Level::Debug);
+        assert_eq!(filter.level("year2025::day5::reference"), Level::Debug);
+        assert_eq!(filter.level("year2025::day7"), Level::DEFAULT);
+        assert_eq!(filter.level("year2024::day7"), Level::Trace);
+        assert_eq!(filter.level("runner::day5"), Level::DEFAULT);
+    }
+
+    #[test]
+    fn strips_crate_from_targets() {
+        assert_eq!(target("aoc2025::year2025::day7"), "year2025::day7");
+        assert_eq!(
+            target("aoc2025::year2025::day7::reference"),
+            "year2025::day7::reference"
+        );
+        assert_eq!(target("aoc2025"), "aoc2025");
+    }
+}
@@ -1,119 +1,526 @@
-use std::{default, fmt::Display, str::FromStr};
-
-use clap::{Parser, Subcommand};
-
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc2025::{
+    Answer, InputType, Part,
+    bench::{self, BenchOptions, BenchReport},
+    client::{self, ClientArgs, Fetched},
+    differential,
+    generate::Rng,
+    input::{self, InputSource},
+    ledger::{self, Ledger, Verdict},
+    log::{self, Filter},
+    output::{self, OutputFormat},
+    registry,
+    runner::{self, DaySelection, Outcome, PartSelection},
+    scaffold,
+    submit::{self, History, Response},
+    trace::{self, TraceTarget},
+};
+use clap::{CommandFactory, Parser, Subcommand, error::ErrorKind};
 
 #[derive(Parser, Debug)]
 struct Args {
     #[command(subcommand)]
-    day: Day,
+    command: Command,
+
+    /// Event year, e.g. `--year 2024 day3`
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = registry::DEFAULT_YEAR, global = true)]
+    year: u16,
+
+    #[arg(short, long, default_value_t = PartSelection::Both, global = true)]
+    part: PartSelection,
+
+    /// `actual`, `example`, or a named example such as `example:2` for `dayN/example_input_2`
+    #[arg(short, long, default_value_t = InputType::Actual, global = true)]
+    input_type: InputType,
+
+    /// Read the input from this file instead, or from stdin when given `-`
+    #[arg(long, global = true)]
+    input: Option<PathBuf>,
+
+    /// Directory containing the `yearYYYY/dayN/input` and `yearYYYY/dayN/example_input` files
+    #[arg(long, env = "AOC_INPUTS_DIR", global = true)]
+    inputs_dir: Option<PathBuf>,
+
+    /// File holding the accepted answers checked by `verify`, defaults to `answers/<year>.json`
+    #[arg(long, env = "AOC_ANSWERS", global = true)]
+    answers: Option<PathBuf>,
+
+    /// Show the simulations of the days that support it, frame by frame: on the `terminal`,
+    /// one `step` at a time, or as files with `dir:<path>`
+    #[arg(long, global = true, num_args = 0..=1, require_equals = true, default_missing_value = "terminal")]
+    trace: Option<TraceTarget>,
 
-    #[arg(short, long)]
-    part: Part,
+    /// Log more to stderr: info with `-v`, debug with `-vv`, trace with `-vvv`
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
 
-    #[arg(short, long, default_value_t = InputType::Actual)]
-    input_type: InputType
+    /// Log levels per module, e.g. `day7=debug` or `info,client=warn`
+    #[arg(long, env = "AOC_LOG", global = true)]
+    log: Option<Filter>,
 }
 
 #[derive(Subcommand, Debug)]
-enum Day {
-    Day1,
-    Day2,
-    Day3,
-    Day4,
-    Day5,
-    Day6,
-    Day7,
-    Day8,
-    Day9,
-    Day10,
-    Day11,
-    Day12,
+enum Command {
+    /// `dayN` is shorthand for `run dayN`
+    #[command(external_subcommand)]
+    Day(Vec<String>),
+    /// List the registered days and whether their parts are implemented
+    List,
+    /// Run several days in one go, e.g. `run all` or `run 1..7,9`
+    Run {
+        days: DaySelection,
+
+        /// `human`, or one record per day and part as `json` lines or `tsv`
+        #[arg(long, default_value_t = OutputFormat::Human)]
+        output: OutputFormat,
+    },
+    /// Time parsing and solving over many iterations
+    Bench {
+        days: DaySelection,
+
+        #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+
+        /// Write the results to this JSON file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare against results previously written with `--save`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Slowdown of the median, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check the recorded answers, failing on any mismatch
+    Verify {
+        /// Days to check, defaults to every day with a recorded answer
+        days: Option<DaySelection>,
+    },
+    /// Record the current answers as accepted
+    Record {
+        days: DaySelection,
+
+        /// Record this answer instead of running the day. Needs a single day and part.
+        #[arg(long, value_parser = <Answer as FromStr>::from_str)]
+        answer: Option<Answer>,
+    },
+    /// Download puzzle inputs into the inputs directory, skipping ones already there
+    Fetch {
+        days: DaySelection,
+
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Submit an answer to the website, unless earlier responses already rule it out
+    Submit {
+        days: DaySelection,
+
+        /// Submit this answer instead of running the day
+        #[arg(long, value_parser = <Answer as FromStr>::from_str)]
+        answer: Option<Answer>,
+
+        /// File remembering every submitted answer and the response to it, defaults to
+        /// `submissions/<year>.json`
+        #[arg(long, env = "AOC_SUBMISSIONS")]
+        history: Option<PathBuf>,
+
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Print a synthetic input for a day, e.g. `generate 4 --size 1000 | aoc day4 --input -`
+    Generate {
+        days: DaySelection,
+
+        /// Size of the input in the day's own unit (lines, grid side, ...), defaults to about
+        /// the size of a real input
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// The same seed and size always generate the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Compare each solver against its reference solution on generated inputs, shrinking any
+    /// disagreement to a minimal counterexample
+    Differential {
+        days: DaySelection,
+
+        /// Number of generated inputs per day and part
+        #[arg(long, default_value_t = 100)]
+        cases: u64,
+
+        /// Seed of the first generated input, the rest follow consecutively
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Size of the generated inputs; small inputs keep the reference solutions fast and the
+        /// counterexamples readable
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Create the module, example input and expected answers for a new day
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle title shown by `list`, defaults to `Day N`
+        #[arg(long)]
+        title: Option<String>,
+    },
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Part {
-    One,
-    Two
+/// Parses the command line, treating `dayN ...` as shorthand for `run dayN ...`.
+fn parse_args() -> Args {
+    let args = Args::parse();
+    let Command::Day(day_args) = &args.command else {
+        return args;
+    };
+    if DaySelection::from_str(&day_args[0]).is_err() {
+        Args::command()
+            .error(
+                ErrorKind::InvalidSubcommand,
+                format!("unrecognized subcommand '{}'", day_args[0]),
+            )
+            .exit();
+    }
+
+    let mut argv: Vec<OsString> = std::env::args_os().collect();
+    let position = argv
+        .iter()
+        .position(|arg| arg.to_str() == Some(&day_args[0]))
+        .expect("the day is one of the arguments");
+    argv.insert(position, "run".into());
+    Args::parse_from(argv)
 }
 
-impl FromStr for Part {
-    type Err = String;
+fn main() {
+    let args = parse_args();
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "1" | "one" | "One" => Ok(Part::One),
-            "2" | "two" | "Two" => Ok(Part::Two),
-            _ => Err(format!("Invalid part: {}", s))
-        }
+    let ledger_path = args
+        .answers
+        .clone()
+        .unwrap_or_else(|| ledger::default_path(args.year));
+    let year = || {
+        registry::year(args.year).unwrap_or_else(|| {
+            fail(&format!(
+                "{0} has no days yet, add one with `new-day --year {0} <day>`",
+                args.year
+            ))
+        })
+    };
+    let select = |days: &DaySelection| days.days(year()).unwrap_or_else(|err| fail(&err));
+
+    let days = match &args.command {
+        Command::Day(_) => unreachable!("rewritten to `run` by parse_args"),
+        Command::NewDay { .. } => vec![],
+        Command::List => year().days.iter().collect(),
+        Command::Run { days, .. }
+        | Command::Bench { days, .. }
+        | Command::Record { days, .. }
+        | Command::Fetch { days, .. }
+        | Command::Submit { days, .. }
+        | Command::Generate { days, .. }
+        | Command::Differential { days, .. } => select(days),
+        Command::Verify { days: Some(days) } => select(days),
+        Command::Verify { days: None } => match Ledger::load(&ledger_path) {
+            Ok(ledger) => ledger
+                .days(&args.input_type)
+                .into_iter()
+                .filter_map(|day| year().get(day))
+                .collect(),
+            Err(err) => fail(&err),
+        },
+    };
+    if args.input.is_some() && days.len() > 1 {
+        eprintln!("error: --input can only be used when running a single day");
+        std::process::exit(2);
     }
-}
 
-pub(crate) trait DayImpl {
-    fn part_one(&self) -> String;
-    fn part_two(&self) -> String;
-}
+    let filter = args.log.clone().unwrap_or_default();
+    log::set_filter(filter.with_verbosity(args.verbose));
 
-#[derive(Default, Debug, Clone, Copy)]
-pub(crate) enum InputType {
-    #[default]
-    Actual,
-    Example
-}
+    if let Some(target) = &args.trace {
+        trace::start(target.clone())
+            .unwrap_or_else(|err| fail(&format!("failed to start tracing to {target}: {err}")));
+    }
 
-impl FromStr for InputType {
-    type Err = String;
+    let source = InputSource::new(args.input.clone(), args.inputs_dir.clone());
+    let parts = args.part.parts();
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "actual" | "a" => Ok(InputType::Actual),
-            "example" | "e" => Ok(InputType::Example),
-            _ => Err(format!("Invalid input type: {}", s))
+    match &args.command {
+        Command::List => {
+            for day in days {
+                let statuses: Vec<String> = [Part::One, Part::Two]
+                    .iter()
+                    .map(|part| format!("part {}: {}", part.number(), runner::status(day, *part)))
+                    .collect();
+                println!(
+                    "Day {:>2}  {:<24} {}",
+                    day.number,
+                    day.title,
+                    statuses.join("  ")
+                );
+            }
         }
-    }
-}
+        Command::Day(_) => unreachable!("rewritten to `run` by parse_args"),
+        Command::Run { output, .. } => {
+            let report = runner::run(&days, &parts, &args.input_type, &source);
+            output::write(&mut std::io::stdout(), &report, &args.input_type, *output)
+                .unwrap_or_else(|err| fail(&format!("failed to write the report: {err}")));
+            if report.has_failures() {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            iterations,
+            warmup,
+            save,
+            baseline,
+            threshold,
+            ..
+        } => {
+            let options = BenchOptions {
+                iterations: *iterations as usize,
+                warmup: *warmup as usize,
+                threshold: *threshold,
+            };
+            let report = bench::bench(&days, &parts, &args.input_type, &source, options);
+            if let Some(path) = save {
+                report.save(path).unwrap_or_else(|err| fail(&err));
+            }
+            if let Some(path) = baseline {
+                let baseline = BenchReport::load(path).unwrap_or_else(|err| fail(&err));
+                if report.compare(&baseline, options.threshold) {
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Verify { .. } => {
+            let ledger = Ledger::load(&ledger_path).unwrap_or_else(|err| fail(&err));
+            let report = runner::run(&days, &parts, &args.input_type, &source);
+            let verifications = ledger.verify(&report, &args.input_type);
+
+            let mut passed = 0;
+            for verification in verifications.iter() {
+                println!("{verification}");
+                if let Verdict::Pass = verification.verdict {
+                    passed += 1;
+                }
+            }
+            println!("{passed}/{} answers verified", verifications.len());
+            if passed != verifications.len() {
+                std::process::exit(1);
+            }
+        }
+        Command::Record { answer, .. } => {
+            let mut ledger = Ledger::load(&ledger_path).unwrap_or_else(|err| fail(&err));
+            let answers: Vec<(u8, Part, Answer)> = match answer {
+                Some(answer) if days.len() == 1 && parts.len() == 1 => {
+                    vec![(days[0].number, parts[0], answer.clone())]
+                }
+                Some(_) => fail("--answer needs a single day and a single --part"),
+                None => {
+                    let report = runner::run(&days, &parts, &args.input_type, &source);
+                    let mut answers = vec![];
+                    for result in report.results() {
+                        match &result.outcome {
+                            Outcome::Answer(answer) => {
+                                answers.push((result.day, result.part, answer.clone()))
+                            }
+                            outcome => {
+                                println!(
+                                    "Day {} part {}: not recorded, {outcome}",
+                                    result.day,
+                                    result.part.number()
+                                )
+                            }
+                        }
+                    }
+                    answers
+                }
+            };
 
-impl Display for InputType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InputType::Actual => write!(f, "actual"),
-            InputType::Example => write!(f, "example"),
+            for (day, part, answer) in answers {
+                match ledger.record(&args.input_type, day, part.number(), answer.clone()) {
+                    Some(previous) if previous != answer => println!(
+                        "Day {day} part {}: recorded {answer} (was {previous})",
+                        part.number()
+                    ),
+                    _ => println!("Day {day} part {}: recorded {answer}", part.number()),
+                }
+            }
+            ledger.save(&ledger_path).unwrap_or_else(|err| fail(&err));
+        }
+        Command::Fetch { client, .. } => {
+            let client = client.client().unwrap_or_else(|err| fail(&err));
+            let dir = args
+                .inputs_dir
+                .clone()
+                .unwrap_or_else(|| input::DEFAULT_INPUTS_DIR.into());
+            for day in days {
+                match client::fetch(&client, &dir, day.year, day.number) {
+                    Ok(Fetched::Downloaded(path)) => {
+                        println!("Day {}: downloaded {}", day.number, path.display())
+                    }
+                    Ok(Fetched::Cached(path)) => {
+                        println!("Day {}: already cached at {}", day.number, path.display())
+                    }
+                    Err(err) => fail(&format!("day {}: {err}", day.number)),
+                }
+            }
+        }
+        Command::Submit {
+            answer,
+            history,
+            client,
+            ..
+        } => {
+            if days.len() != 1 || parts.len() != 1 {
+                fail("submit needs a single day and a single --part");
+            }
+            if !matches!(args.input_type, InputType::Actual) {
+                fail("only answers to the actual input can be submitted");
+            }
+            let (year, day, part) = (days[0].year, days[0].number, parts[0]);
+            let answer = match answer {
+                Some(answer) => answer.clone(),
+                None => match &runner::run(&days, &parts, &args.input_type, &source).results()[0]
+                    .outcome
+                {
+                    Outcome::Answer(answer) => answer.clone(),
+                    outcome => fail(&format!("nothing to submit, {outcome}")),
+                },
+            };
+
+            let client = client.client().unwrap_or_else(|err| fail(&err));
+            let history_path = history
+                .clone()
+                .unwrap_or_else(|| submit::default_history_path(year));
+            let mut history = History::load(&history_path).unwrap_or_else(|err| fail(&err));
+            let response = submit::submit(&client, &mut history, year, day, part.number(), &answer)
+                .unwrap_or_else(|err| {
+                    fail(&format!(
+                        "Day {day} part {}: not submitted, {err}",
+                        part.number()
+                    ))
+                });
+            history.save(&history_path).unwrap_or_else(|err| fail(&err));
+            println!("Day {day} part {}: {answer} is {response}", part.number());
+
+            if response == Response::Correct {
+                let mut ledger = Ledger::load(&ledger_path).unwrap_or_else(|err| fail(&err));
+                ledger.record(&args.input_type, day, part.number(), answer);
+                ledger.save(&ledger_path).unwrap_or_else(|err| fail(&err));
+            }
+        }
+        Command::Generate { size, seed, .. } => {
+            let [day] = days[..] else {
+                fail("generate needs a single day");
+            };
+            let Some(generator) = day.generator else {
+                fail(&format!("day {} has no input generator", day.number));
+            };
+            let size = size.unwrap_or(generator.default_size);
+            print!("{}", (generator.generate)(&mut Rng::new(*seed), size));
+        }
+        Command::Differential {
+            cases, seed, size, ..
+        } => {
+            let mut failed = false;
+            for day in days {
+                let missing = match (day.generator, day.reference) {
+                    (Some(_), Some(_)) => None,
+                    (None, Some(_)) => Some("no input generator"),
+                    (Some(_), None) => Some("no reference solution"),
+                    (None, None) => Some("no input generator or reference solution"),
+                };
+                if let Some(missing) = missing {
+                    println!("Day {}: {missing}", day.number);
+                    continue;
+                }
+                for part in &parts {
+                    match differential::check(day, *part, *seed..seed + cases, *size) {
+                        Ok(()) => println!(
+                            "Day {} part {}: {cases} inputs agree",
+                            day.number,
+                            part.number()
+                        ),
+                        Err(counterexample) => {
+                            print!("{counterexample}");
+                            failed = true;
+                        }
+                    }
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::NewDay { day, title } => {
+            let title = title.clone().unwrap_or_else(|| format!("Day {day}"));
+            let source_dir = Path::new(scaffold::DEFAULT_SOURCE_DIR);
+            let created = scaffold::new_day(source_dir, args.year, *day, &title)
+                .unwrap_or_else(|err| fail(&err));
+            for path in created {
+                println!("created {}", path.display());
+            }
         }
     }
 }
 
-fn main() {
-    let args = Args::parse();
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(1);
+}
 
-    let day_impl: Box<dyn DayImpl> = match args.day {
-        Day::Day1 => Box::new(day1::Day::new(args.input_type)),
-        Day::Day2 => Box::new(day2::Day::new(args.input_type)),
-        Day::Day3 => Box::new(day3::Day::new(args.input_type)),
-        Day::Day4 => Box::new(day4::Day::new(args.input_type)),
-        Day::Day5 => Box::new(day5::Day::new(args.input_type)),
-        Day::Day6 => Box::new(day6::Day::new(args.input_type)),
-        Day::Day7 => Box::new(day7::Day::new(args.input_type)),
-        Day::Day8 => Box::new(day8::Day::new(args.input_type)),
-        Day::Day9 => Box::new(day9::Day::new(args.input_type)),
-        Day::Day10 => Box::new(day10::Day::new(args.input_type)),
-        Day::Day11 => Box::new(day11::Day::new(args.input_type)),
-        Day::Day12 => Box::new(day12::Day::new(args.input_type)),
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_answers_given_as_numbers() {
+        let args = Args::try_parse_from([
+            "aoc", "record", "1", "-p", "1", "-i", "example", "--answer", "3",
+        ])
+        .unwrap();
+        let Command::Record {
+            answer: Some(answer),
+            ..
+        } = args.command
+        else {
+            panic!(
+                "expected a record command with an answer, got {:?}",
+                args.command
+            );
+        };
+        assert!(matches!(answer, Answer::Unsigned(3)));
+
+        let mut ledger = Ledger::default();
+        ledger.record(&args.input_type, 1, 1, answer);
+        let json = serde_json::to_string(&ledger).unwrap();
+        assert_eq!(json, r#"{"example":{"1":{"1":3}}}"#);
+    }
 
-    match args.part {
-        Part::One => println!("Part 1: {}", day_impl.part_one()),
-        Part::Two => eprintln!("Part 2: {}", day_impl.part_two()),
+    #[test]
+    fn submits_answers_given_as_numbers() {
+        let args =
+            Args::try_parse_from(["aoc", "submit", "1", "-p", "1", "--answer", "100"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Submit {
+                answer: Some(Answer::Unsigned(100)),
+                ..
+            }
+        ));
     }
 }
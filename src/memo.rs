@@ -0,0 +1,311 @@
+//! Memoized recursion and dynamic programming tables, for the counting puzzles.
+//!
+//! [`Memo::solve`] runs a recursive definition without recursing: the definition looks its
+//! subproblems up with [`Deps::get`], giving up with `None` when one isn't known yet, and the
+//! solver works through the missing subproblems on an explicit stack before trying again. Deep
+//! inputs can't overflow the call stack, and the definition reads like the recursion it replaces:
+//!
+//! ```
+//! use aoc2025::memo::Memo;
+//!
+//! let mut fibonacci = Memo::new();
+//! let n = fibonacci.solve(90u64, |&n, deps| match n {
+//!     0 | 1 => Some(n),
+//!     n => Some(deps.get(n - 1)? + deps.get(n - 2)?),
+//! });
+//! assert_eq!(n, 2880067194370816120);
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+};
+
+use crate::{
+    grid::{Grid, Point},
+    trace::{self, Frame},
+};
+
+/// How a [`Memo`] has been used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Lookups answered from the cache.
+    pub hits: usize,
+    /// Lookups of subproblems that had to be solved first.
+    pub misses: usize,
+    /// Calls to the definition, including the retries after solving missing subproblems.
+    pub evaluations: usize,
+}
+
+impl Stats {
+    /// The share of lookups answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evaluations",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.evaluations
+        )
+    }
+}
+
+/// The values of a recursive definition, solved on demand and kept for later lookups.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of `key` under `definition`, which computes a key's value from the values of
+    /// the subproblems it looks up with [`Deps::get`], or returns `None` when a lookup does.
+    /// Solved keys are cached across calls, so `definition` must stay the same for a memo.
+    ///
+    /// Panics if the definition depends on itself in a cycle, or returns `None` without looking
+    /// up a missing key.
+    pub fn solve(
+        &mut self,
+        key: K,
+        mut definition: impl FnMut(&K, &mut Deps<K, V>) -> Option<V>,
+    ) -> V {
+        let mut stack = vec![key.clone()];
+        // Keys that gave up waiting for the keys above them on the stack, which all descend from
+        // them, so depending on a waiting key is a cycle.
+        let mut waiting = HashSet::new();
+        while let Some(current) = stack.last() {
+            if self.cache.contains_key(current) {
+                stack.pop();
+                continue;
+            }
+
+            let mut deps = Deps {
+                cache: &self.cache,
+                stats: &mut self.stats,
+                missing: vec![],
+            };
+            deps.stats.evaluations += 1;
+            let value = definition(current, &mut deps);
+            let missing = deps.missing;
+            match value {
+                Some(value) => {
+                    let current = stack.pop().expect("the stack holds the current key");
+                    waiting.remove(&current);
+                    self.cache.insert(current, value);
+                }
+                None => {
+                    assert!(
+                        !missing.is_empty(),
+                        "the definition gave up without looking up a missing key"
+                    );
+                    waiting.insert(current.clone());
+                    for dependency in missing {
+                        assert!(
+                            !waiting.contains(&dependency),
+                            "the definition depends on itself in a cycle"
+                        );
+                        stack.push(dependency);
+                    }
+                }
+            }
+        }
+        self.cache[&key].clone()
+    }
+
+    /// The cached value of `key`, if it has been solved.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Number of solved keys.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Logs the cache statistics at debug level, under the `memo` target, and shows them as a
+    /// frame when tracing.
+    pub fn report(&self, name: &str) {
+        debug!("{name}: {} cached, {}", self.len(), self.stats);
+        trace::frame(|| {
+            Frame::new(
+                format!("{name} memo"),
+                format!("{} cached\n{}", self.len(), self.stats),
+            )
+        });
+    }
+}
+
+/// The lookups available to a definition while [`Memo::solve`] evaluates it.
+pub struct Deps<'a, K, V> {
+    cache: &'a HashMap<K, V>,
+    stats: &'a mut Stats,
+    missing: Vec<K>,
+}
+
+impl<K: Hash + Eq, V: Clone> Deps<'_, K, V> {
+    /// The value of the subproblem `key`, or `None` when it hasn't been solved yet, in which case
+    /// the definition should give up by returning `None` too, usually with `?`.
+    pub fn get(&mut self, key: K) -> Option<V> {
+        match self.cache.get(&key) {
+            Some(value) => {
+                self.stats.hits += 1;
+                Some(value.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                if !self.missing.contains(&key) {
+                    self.missing.push(key);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Fills a table of `len` entries in order, computing entry `i` from `i` and the entries before
+/// it, for bottom-up dynamic programming.
+pub fn table<V>(len: usize, mut entry: impl FnMut(usize, &[V]) -> V) -> Vec<V> {
+    let mut table = Vec::with_capacity(len);
+    for i in 0..len {
+        let value = entry(i, &table);
+        table.push(value);
+    }
+    table
+}
+
+/// Fills a `width` by `height` grid row by row, computing each cell from its point and the cells
+/// filled before it.
+pub fn grid_table<V>(
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(Point, &Filled<V>) -> V,
+) -> Grid<V> {
+    let mut filled = Filled {
+        width,
+        cells: Vec::with_capacity(width * height),
+    };
+    for point in (0..height).flat_map(|y| (0..width).map(move |x| Point::new(x, y))) {
+        let value = cell(point, &filled);
+        filled.cells.push(value);
+    }
+    Grid::from_vec(width, filled.cells)
+}
+
+/// The cells of a [`grid_table`] filled so far: every row above, and the cells to the left.
+pub struct Filled<V> {
+    width: usize,
+    cells: Vec<V>,
+}
+
+impl<V> Filled<V> {
+    /// The value at `point`, `None` if it's outside the grid or not filled yet.
+    pub fn get(&self, point: Point) -> Option<&V> {
+        if point.x >= self.width {
+            return None;
+        }
+        self.cells.get(point.y * self.width + point.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deep_recursion_uses_no_call_stack() {
+        let mut memo = Memo::new();
+        let sum = memo.solve(200_000u64, |&n, deps| match n {
+            0 => Some(0),
+            n => Some(n + deps.get(n - 1)?),
+        });
+        assert_eq!(sum, 20_000_100_000);
+        assert_eq!(memo.len(), 200_001);
+    }
+
+    #[test]
+    fn counts_hits_and_reuses_the_cache() {
+        let mut memo = Memo::new();
+        let paths = |&(x, y): &(u8, u8), deps: &mut Deps<(u8, u8), u64>| match (x, y) {
+            (0, _) | (_, 0) => Some(1),
+            _ => {
+                let (left, up) = (deps.get((x - 1, y)), deps.get((x, y - 1)));
+                Some(left? + up?)
+            }
+        };
+        assert_eq!(memo.solve((2, 2), paths), 6);
+        let stats = memo.stats();
+        assert!(stats.hits > 0 && stats.misses > 0);
+
+        assert_eq!(memo.solve((2, 1), paths), 3);
+        assert_eq!(memo.stats().evaluations, stats.evaluations);
+    }
+
+    #[test]
+    fn shared_subproblems_are_not_cycles() {
+        // 3 needs 1 and 2, and 2 needs 1 again while that's still queued for 3.
+        let mut memo = Memo::new();
+        let value = memo.solve(3u8, |&n, deps| match n {
+            0 => Some(1u32),
+            3 => Some(deps.get(1)? + deps.get(2)?),
+            n => Some((0..n).map(|m| deps.get(m)).sum::<Option<u32>>()?),
+        });
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle")]
+    fn cycles_panic() {
+        Memo::<u8, u8>::new().solve(0, |&n, deps| deps.get((n + 1) % 3));
+    }
+
+    #[test]
+    fn tables_fill_in_order() {
+        let fibonacci = table(10, |i, done: &[u32]| match i {
+            0 | 1 => i as u32,
+            i => done[i - 1] + done[i - 2],
+        });
+        assert_eq!(fibonacci[9], 34);
+
+        let paths = grid_table(3, 3, |point, filled| {
+            let left = point.offset(-1, 0).and_then(|p| filled.get(p));
+            let up = point.offset(0, -1).and_then(|p| filled.get(p));
+            match (left, up) {
+                (None, None) => 1,
+                (left, up) => left.unwrap_or(&0) + up.unwrap_or(&0),
+            }
+        });
+        assert_eq!(paths[Point::new(2, 2)], 6);
+    }
+}
@@ -0,0 +1,145 @@
+//! Machine-readable renderings of a [`Report`], for scripts consuming the results of a run.
+
+use std::{fmt::Display, io, str::FromStr};
+
+use serde::Serialize;
+
+use crate::{
+    InputType,
+    answer::Answer,
+    runner::{Outcome, PartResult, Report},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The report as printed by its `Display` impl.
+    #[default]
+    Human,
+    /// One JSON object per line and part.
+    Json,
+    /// Tab-separated values with a header row.
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(OutputFormat::Human),
+            "json" => Ok(OutputFormat::Json),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!(
+                "Invalid output format: {s} (expected human, json or tsv)"
+            )),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Human => write!(f, "human"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Tsv => write!(f, "tsv"),
+        }
+    }
+}
+
+/// One day and part of a run.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_type: String,
+    /// Hex [`crate::input::hash`] of the input, `None` when it couldn't be loaded.
+    pub input_hash: Option<String>,
+    /// `ok`, `unimplemented`, `panicked`, `missing_input` or `invalid_input`.
+    pub status: &'static str,
+    /// Set when the status is `ok`.
+    pub answer: Option<&'a Answer>,
+    /// Why there is no answer, for the statuses other than `ok` and `unimplemented`.
+    pub message: Option<String>,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(result: &'a PartResult, input_type: &InputType) -> Self {
+        let (answer, message) = match &result.outcome {
+            Outcome::Answer(answer) => (Some(answer), None),
+            Outcome::Unimplemented => (None, None),
+            outcome => (None, Some(outcome.to_string())),
+        };
+        Record {
+            year: result.year,
+            day: result.day,
+            part: result.part.number(),
+            input_type: input_type.to_string(),
+            input_hash: result.input_hash.map(|hash| format!("{hash:016x}")),
+            status: result.outcome.status(),
+            answer,
+            message,
+            parse_ns: result.parse_time.as_nanos(),
+            solve_ns: result.solve_time.as_nanos(),
+        }
+    }
+}
+
+/// Writes `report` to `out` in the given format.
+pub fn write(
+    out: &mut impl io::Write,
+    report: &Report,
+    input_type: &InputType,
+    format: OutputFormat,
+) -> io::Result<()> {
+    let records = report
+        .results()
+        .iter()
+        .map(|result| Record::new(result, input_type));
+    match format {
+        OutputFormat::Human => write!(out, "{report}"),
+        OutputFormat::Json => {
+            for record in records {
+                serde_json::to_writer(&mut *out, &record)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+        OutputFormat::Tsv => {
+            writeln!(
+                out,
+                "year\tday\tpart\tinput_type\tinput_hash\tstatus\tanswer\tmessage\tparse_ns\tsolve_ns"
+            )?;
+            for record in records {
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    record.input_type,
+                    record.input_hash.unwrap_or_default(),
+                    record.status,
+                    record
+                        .answer
+                        .map(|answer| escape(&answer.to_string()))
+                        .unwrap_or_default(),
+                    escape(&record.message.unwrap_or_default()),
+                    record.parse_ns,
+                    record.solve_ns,
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Keeps a field on one line and in one column.
+fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,411 @@
+//! Puzzle input parsing: errors pointing at the offending text, and [`Span`], a small toolkit
+//! for taking inputs apart while remembering where each piece came from.
+
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+use crate::grid::Grid;
+
+/// A problem with a puzzle input, pointing at the line and column where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, since parsers don't know which day they belong to.
+    pub day: Option<u8>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The full line containing the problem.
+    pub snippet: String,
+    /// Width of the offending text in characters, at least 1.
+    pub width: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error pointing at `fragment`, which must be a slice of `input`. Fragments from
+    /// elsewhere point at the end of the input.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = fragment.as_ptr() as usize;
+        let base = input.as_ptr() as usize;
+        let offset = match start.checked_sub(base) {
+            Some(offset) if offset + fragment.len() <= input.len() => offset,
+            _ => input.len(),
+        };
+        Self::at_offset(input, offset, fragment.chars().count(), message)
+    }
+
+    /// An error pointing at `width` characters starting from byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, width: usize, message: impl Into<String>) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+
+        ParseError {
+            day: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: snippet.into(),
+            width: width.max(1),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+/// Renders the error as a caret diagnostic:
+///
+/// ```text
+/// error: invalid cell 'x'
+///  --> day4:3:4
+///   |
+/// 3 | ..@x@.
+///   |    ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = match self.day {
+            Some(day) => format!("day{day}:{}:{}", self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{gutter}--> {location}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of a puzzle input that knows where in the input it is, so any error about it can point
+/// at it. Its methods take the input apart into smaller spans, and parse spans into values.
+///
+/// ```
+/// use aoc2025::parse::Span;
+///
+/// let input = "1-3,5-8\n";
+/// let ranges: Vec<(u32, u32)> = Span::new(input)
+///     .trim()
+///     .list(",", |range| {
+///         let (first, last) = range.split_once("-", "Expected a range like 1-3")?;
+///         Ok((first.int()?, last.int()?))
+///     })
+///     .unwrap();
+/// assert_eq!(ranges, [(1, 3), (5, 8)]);
+///
+/// let err = Span::new("1-3,5+8").list(",", |range| range.split_once("-", "Expected a range"));
+/// assert_eq!(err.unwrap_err().column, 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    input: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of `input`.
+    pub fn new(input: &'a str) -> Self {
+        Span {
+            input,
+            start: 0,
+            end: input.len(),
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        &self.input[self.start..self.end]
+    }
+
+    /// Length in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// An error pointing at this span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at_offset(
+            self.input,
+            self.start,
+            self.as_str().chars().count(),
+            message,
+        )
+    }
+
+    /// The part of this span at the byte `range` within it.
+    pub fn slice(&self, range: Range<usize>) -> Span<'a> {
+        assert!(range.start <= range.end && range.end <= self.len());
+        Span {
+            input: self.input,
+            start: self.start + range.start,
+            end: self.start + range.end,
+        }
+    }
+
+    /// The span of `part`, which must be a slice of this span's text.
+    fn of(&self, part: &'a str) -> Span<'a> {
+        let start = part.as_ptr() as usize - self.as_str().as_ptr() as usize;
+        self.slice(start..start + part.len())
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.of(self.as_str().trim())
+    }
+
+    pub fn trim_end(&self) -> Span<'a> {
+        self.of(self.as_str().trim_end())
+    }
+
+    /// The first character and everything after it, `None` when the span is empty.
+    pub fn split_first(&self) -> Option<(Span<'a>, Span<'a>)> {
+        let first = self.as_str().chars().next()?;
+        Some((
+            self.slice(0..first.len_utf8()),
+            self.slice(first.len_utf8()..self.len()),
+        ))
+    }
+
+    /// The text before and after the first `separator`, failing with `message` if there is none.
+    pub fn split_once(
+        &self,
+        separator: &str,
+        message: impl Into<String>,
+    ) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let Some((before, after)) = self.as_str().split_once(separator) else {
+            return Err(self.error(message));
+        };
+        Ok((self.of(before), self.of(after)))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.as_str()
+            .split(separator)
+            .map(move |part| span.of(part))
+    }
+
+    /// The whitespace-separated words of the span.
+    pub fn words(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.as_str()
+            .split_whitespace()
+            .map(move |word| span.of(word))
+    }
+
+    /// Every line, without its line ending. Trailing blank lines are left out.
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = self.trim_end();
+        span.as_str()
+            .lines()
+            .map(move |line| span.of(line.trim_end_matches('\r')))
+    }
+
+    /// Every character, with the span covering it.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Span<'a>)> + 'a {
+        let span = *self;
+        self.as_str()
+            .char_indices()
+            .map(move |(i, c)| (c, span.slice(i..i + c.len_utf8())))
+    }
+
+    /// An unsigned integer, digits only.
+    pub fn int<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.number(self.as_str())
+    }
+
+    /// An integer with an optional `-` or `+` sign.
+    pub fn signed<T: FromStr>(&self) -> Result<T, ParseError> {
+        let text = self.as_str();
+        self.number(text.strip_prefix(['-', '+']).unwrap_or(text))
+    }
+
+    /// Parses the whole span as a number, after checking its `digits` are all there are.
+    fn number<T: FromStr>(&self, digits: &str) -> Result<T, ParseError> {
+        let text = self.as_str();
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(self.error(format!("Expected a number, found {text:?}")));
+        }
+        text.parse()
+            .map_err(|_| self.error(format!("Number out of range: {text}")))
+    }
+
+    /// Parses every `separator`-separated item with `item`, trimming whitespace around each.
+    pub fn list<T>(
+        &self,
+        separator: &'a str,
+        item: impl FnMut(Span<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.split(separator)
+            .map(|part| part.trim())
+            .map(item)
+            .collect()
+    }
+
+    /// Parses every line with `line`.
+    pub fn lines_of<T>(
+        &self,
+        line: impl FnMut(Span<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.lines().map(line).collect()
+    }
+
+    /// Splits the span into exactly `N` sections separated by blank lines.
+    pub fn sections<const N: usize>(&self) -> Result<[Span<'a>; N], ParseError> {
+        let mut sections: Vec<Span<'a>> = vec![];
+        let mut in_section = false;
+        for line in self.lines() {
+            let blank = line.as_str().trim().is_empty();
+            match sections.last_mut() {
+                Some(section) if in_section && !blank => section.end = line.end,
+                _ if !blank => sections.push(line),
+                _ => {}
+            }
+            in_section = !blank;
+        }
+
+        let found = sections.len();
+        sections.try_into().map_err(|_| {
+            let end = self.trim_end().len();
+            self.slice(end..end).error(format!(
+                "Expected {N} sections separated by blank lines, found {found}"
+            ))
+        })
+    }
+
+    /// Splits a block of lines into columns at the character columns that are blank on every
+    /// line, returning each column's span on every line. Lines shorter than the block count as
+    /// padded with spaces, so their spans may be shorter or empty. Only ASCII text is supported.
+    pub fn columns(&self) -> Result<Vec<Vec<Span<'a>>>, ParseError> {
+        if let Some((c, at)) = self.chars().find(|(c, _)| !c.is_ascii()) {
+            return Err(at.error(format!("Unexpected non-ASCII character: {c}")));
+        }
+        let lines: Vec<Span<'a>> = self.lines().collect();
+        let width = lines.iter().map(Span::len).max().unwrap_or_default();
+        let blank = |x: usize| {
+            lines
+                .iter()
+                .all(|line| matches!(line.as_str().as_bytes().get(x), None | Some(b' ')))
+        };
+
+        let mut columns = vec![];
+        let mut x = 0;
+        while x < width {
+            if blank(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && !blank(x) {
+                x += 1;
+            }
+            columns.push(
+                lines
+                    .iter()
+                    .map(|line| line.slice(start.min(line.len())..x.min(line.len())))
+                    .collect(),
+            );
+        }
+        Ok(columns)
+    }
+
+    /// A character map, one row per line, converting each character with `cell`.
+    pub fn grid<T>(
+        &self,
+        cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_span(*self, cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(err: ParseError) -> (usize, usize, String) {
+        (err.line, err.column, err.message)
+    }
+
+    #[test]
+    fn numbers_point_at_themselves() {
+        let line = Span::new("x: 12 -7 +3 abc 99999999999");
+        let words: Vec<Span> = line.words().collect();
+        assert_eq!(words[1].int::<u8>(), Ok(12));
+        assert_eq!(words[2].signed::<i32>(), Ok(-7));
+        assert_eq!(words[3].signed::<i32>(), Ok(3));
+        assert!(words[2].int::<i32>().is_err());
+        assert_eq!(
+            position(words[4].int::<u32>().unwrap_err()),
+            (1, 13, "Expected a number, found \"abc\"".into())
+        );
+        assert_eq!(
+            position(words[5].int::<u32>().unwrap_err()),
+            (1, 17, "Number out of range: 99999999999".into())
+        );
+    }
+
+    #[test]
+    fn lists_and_lines_keep_positions() {
+        let input = "1, 2,3\n4,x\n\n";
+        let err = Span::new(input)
+            .lines_of(|line| line.list(",", |item| item.int::<u8>()))
+            .unwrap_err();
+        assert_eq!(
+            position(err),
+            (2, 3, "Expected a number, found \"x\"".into())
+        );
+
+        let lines = Span::new("a\r\nb\r\n").lines_of(|line| Ok(line.as_str()));
+        assert_eq!(lines, Ok(vec!["a", "b"]));
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = "\n1-3\n4-5\n\n\n7\n8\n";
+        let [ranges, ids] = Span::new(input).sections().unwrap();
+        assert_eq!(ranges.as_str(), "1-3\n4-5");
+        assert_eq!(ids.as_str(), "7\n8");
+        assert_eq!(ids.lines().nth(1).unwrap().error("").line, 7);
+
+        let err = Span::new("1-3\n").sections::<2>().unwrap_err();
+        assert_eq!(
+            position(err),
+            (
+                1,
+                4,
+                "Expected 2 sections separated by blank lines, found 1".into()
+            )
+        );
+    }
+
+    #[test]
+    fn columns_split_on_blank_columns() {
+        let input = "12  3\n 4 56\n+   *\n";
+        let columns: Vec<Vec<&str>> = Span::new(input)
+            .columns()
+            .unwrap()
+            .iter()
+            .map(|column| column.iter().map(Span::as_str).collect())
+            .collect();
+        assert_eq!(columns, [vec!["12", " 4", "+ "], vec![" 3", "56", " *"]]);
+        assert!(Span::new("1é\n").columns().is_err());
+    }
+}
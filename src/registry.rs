@@ -0,0 +1,110 @@
+//! Every day module, collected by `build.rs` from the `src/yearYYYY/dayN` directories. Each
+//! module registers itself with `register_day!`.
+
+use crate::{Solver, differential::Reference, generate::Generator};
+
+/// The year used when none is given.
+pub const DEFAULT_YEAR: u16 = 2025;
+
+/// Number of puzzles in a year's calendar: 25 up to 2024, 12 since 2025.
+pub fn day_count(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// The registered days of a year.
+#[derive(Debug)]
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Registration],
+}
+
+impl Year {
+    pub fn day_count(&self) -> u8 {
+        day_count(self.number)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&'static Registration> {
+        self.days
+            .iter()
+            .find(|registration| registration.number == day)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub year: u16,
+    pub number: u8,
+    pub title: &'static str,
+    pub constructor: fn() -> Box<dyn Solver>,
+    pub generator: Option<Generator>,
+    pub reference: Option<Reference>,
+}
+
+impl Registration {
+    /// A registration without any of the optional extras.
+    pub const fn new(
+        year: u16,
+        number: u8,
+        title: &'static str,
+        constructor: fn() -> Box<dyn Solver>,
+    ) -> Self {
+        Registration {
+            year,
+            number,
+            title,
+            constructor,
+            generator: None,
+            reference: None,
+        }
+    }
+
+    pub fn solver(&self) -> Box<dyn Solver> {
+        (self.constructor)()
+    }
+}
+
+/// Declares the `REGISTRATION` of the day module it's invoked in, constructing the module's
+/// `Day` unit struct, along with `parse`, `part_one` and `part_two` functions taking the raw
+/// input. The optional extras of [`Registration`] follow as `name = value`, e.g.
+/// `register_day!(1, "Secret Entrance", generator = GENERATOR)`. The year comes from the `YEAR`
+/// constant `build.rs` declares in each year module. The day modules are declared after this
+/// module, so they can use it without an import.
+macro_rules! register_day {
+    ($number:literal, $title:literal $(, $extra:ident = $value:expr)* $(,)?) => {
+        pub const REGISTRATION: $crate::registry::Registration = $crate::registry::Registration {
+            $($extra: Some($value),)*
+            ..$crate::registry::Registration::new(super::YEAR, $number, $title, || Box::new(Day))
+        };
+
+        /// Parses the puzzle input into the input shared by both parts.
+        pub fn parse(input: &str) -> Result<<Day as $crate::DayImpl>::Input, $crate::ParseError> {
+            <Day as $crate::DayImpl>::parse(input)
+        }
+
+        /// Parses the puzzle input and solves part one.
+        pub fn part_one(input: &str) -> Result<$crate::Answer, $crate::ParseError> {
+            Ok(<Day as $crate::DayImpl>::part_one(&parse(input)?))
+        }
+
+        /// Parses the puzzle input and solves part two.
+        pub fn part_two(input: &str) -> Result<$crate::Answer, $crate::ParseError> {
+            Ok(<Day as $crate::DayImpl>::part_two(&parse(input)?))
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/registrations.rs"));
+
+/// Every year with at least one registered day, in order.
+pub fn years() -> &'static [Year] {
+    YEARS
+}
+
+pub fn year(number: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|year| year.number == number)
+}
+
+/// Every registered day of every year.
+pub fn all() -> impl Iterator<Item = &'static Registration> {
+    YEARS.iter().flat_map(|year| year.days)
+}
@@ -0,0 +1,478 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    InputType, Part, Solver,
+    answer::Answer,
+    input::{self, InputError, InputSource},
+    parse::ParseError,
+    registry::{Registration, Year},
+    trace,
+};
+
+/// Days to run, parsed from `all`, `3`, `1..7` (inclusive) or a comma-separated mix like `1..3,5`.
+/// Which days exist depends on the year, so they are only checked by [`DaySelection::days`].
+#[derive(Debug, Clone)]
+pub enum DaySelection {
+    All,
+    Days(Vec<RangeInclusive<u8>>),
+}
+
+impl DaySelection {
+    /// The registered days of `year` in the selection. Ranges skip days without a solution, but
+    /// a day asked for by itself must have one.
+    pub fn days(&self, year: &Year) -> Result<Vec<&'static Registration>, String> {
+        let items = match self {
+            DaySelection::All => return Ok(year.days.iter().collect()),
+            DaySelection::Days(items) => items,
+        };
+
+        let mut days = vec![];
+        for item in items {
+            if *item.end() > year.day_count() {
+                return Err(format!(
+                    "{} only has {} days",
+                    year.number,
+                    year.day_count()
+                ));
+            }
+            if item.start() == item.end() {
+                let day = year.get(*item.start()).ok_or_else(|| {
+                    format!("day {} of {} has no solution", item.start(), year.number)
+                })?;
+                days.push(day);
+            } else {
+                days.extend(item.clone().filter_map(|day| year.get(day)));
+            }
+        }
+        days.sort_by_key(|day| day.number);
+        days.dedup_by_key(|day| day.number);
+        Ok(days)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        let parse_day = |day: &str| -> Result<u8, String> {
+            let day = day.trim().trim_start_matches("day");
+            match day.parse() {
+                Ok(n) if (1..=25).contains(&n) => Ok(n),
+                _ => Err(format!("Invalid day: {day}")),
+            }
+        };
+
+        let mut days = vec![];
+        for item in s.split(",") {
+            match item.split_once("..") {
+                Some((first, last)) => {
+                    let (first, last) = (parse_day(first)?, parse_day(last)?);
+                    if first > last {
+                        return Err(format!("Invalid day range: {item}"));
+                    }
+                    days.push(first..=last);
+                }
+                None => {
+                    let day = parse_day(item)?;
+                    days.push(day..=day);
+                }
+            }
+        }
+        Ok(DaySelection::Days(days))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartSelection {
+    One,
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(&self) -> Vec<Part> {
+        match self {
+            PartSelection::One => vec![Part::One],
+            PartSelection::Two => vec![Part::Two],
+            PartSelection::Both => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+impl FromStr for PartSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" | "Both" | "b" => Ok(PartSelection::Both),
+            s => match Part::from_str(s)? {
+                Part::One => Ok(PartSelection::One),
+                Part::Two => Ok(PartSelection::Two),
+            },
+        }
+    }
+}
+
+impl Display for PartSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartSelection::One => write!(f, "1"),
+            PartSelection::Two => write!(f, "2"),
+            PartSelection::Both => write!(f, "both"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Answer(Answer),
+    Unimplemented,
+    Panicked(String),
+    /// Shared by the results of every part of the day.
+    MissingInput(Arc<InputError>),
+    InvalidInput(ParseError),
+}
+
+impl Outcome {
+    /// Short machine-readable name of the outcome.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Answer(_) => "ok",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::MissingInput(_) => "missing_input",
+            Outcome::InvalidInput(_) => "invalid_input",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Unimplemented => write!(f, "not implemented"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::MissingInput(err) => write!(f, "{err}"),
+            Outcome::InvalidInput(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    /// [`input::hash`] of the input, if it could be loaded.
+    pub input_hash: Option<u64>,
+    /// Time spent parsing the day's input, shared by both of its parts.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    results: Vec<PartResult>,
+}
+
+impl Report {
+    pub fn results(&self) -> &[PartResult] {
+        &self.results
+    }
+
+    /// Whether any part panicked or could not be run. Unimplemented days are not failures.
+    pub fn has_failures(&self) -> bool {
+        self.results.iter().any(|result| {
+            matches!(
+                result.outcome,
+                Outcome::Panicked(_) | Outcome::MissingInput(_) | Outcome::InvalidInput(_)
+            )
+        })
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let multiple_days = self
+            .results
+            .windows(2)
+            .any(|pair| pair[0].day != pair[1].day);
+        let mut last_day = None;
+
+        for (i, result) in self.results.iter().enumerate() {
+            let day_failed = matches!(
+                result.outcome,
+                Outcome::MissingInput(_) | Outcome::InvalidInput(_)
+            );
+            // Every part of the day shares the error, so it's only shown once.
+            if day_failed && i > 0 && self.results[i - 1].day == result.day {
+                continue;
+            }
+            let line = match result.outcome {
+                Outcome::MissingInput(_) | Outcome::InvalidInput(_) => {
+                    format!("{}", result.outcome)
+                }
+                _ => format!("Part {}: {}", result.part.number(), result.outcome),
+            };
+            if !multiple_days {
+                writeln!(f, "{line}")?;
+                continue;
+            }
+            if last_day != Some(result.day) {
+                match day_failed {
+                    true => writeln!(f, "Day {}", result.day)?,
+                    false => writeln!(f, "Day {} (parsed in {:?})", result.day, result.parse_time)?,
+                }
+                last_day = Some(result.day);
+            }
+            match result.outcome {
+                Outcome::Answer(_) => writeln!(f, "  {line:<40} {:?}", result.solve_time)?,
+                _ => writeln!(f, "  {}", line.replace('\n', "\n  "))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn run(
+    days: &[&Registration],
+    parts: &[Part],
+    input_type: &InputType,
+    source: &InputSource,
+) -> Report {
+    let mut results = vec![];
+    for day in days {
+        let input = match input::load(day.year, day.number, input_type, source) {
+            Ok(input) => input,
+            Err(err) => {
+                let err = Arc::new(err);
+                results.extend(parts.iter().map(|part| PartResult {
+                    year: day.year,
+                    day: day.number,
+                    part: *part,
+                    outcome: Outcome::MissingInput(Arc::clone(&err)),
+                    input_hash: None,
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                }));
+                continue;
+            }
+        };
+        let input_hash = Some(input::hash(&input));
+        debug!(
+            "day {}: loaded {} bytes of {input_type} input",
+            day.number,
+            input.len()
+        );
+        let solver = day.solver();
+
+        let start = Instant::now();
+        let parsed = match catch_panic(|| solver.parse(&input)) {
+            Ok(Ok(parsed)) => Ok(parsed),
+            Ok(Err(err)) => {
+                let err = err.with_day(day.number);
+                results.extend(parts.iter().map(|part| PartResult {
+                    year: day.year,
+                    day: day.number,
+                    part: *part,
+                    outcome: Outcome::InvalidInput(err.clone()),
+                    input_hash,
+                    parse_time: Duration::ZERO,
+                    solve_time: Duration::ZERO,
+                }));
+                continue;
+            }
+            Err(message) => Err(message),
+        };
+        let parse_time = start.elapsed();
+        debug!("day {}: parsed in {parse_time:?}", day.number);
+
+        for part in parts {
+            trace::set_part(day.number, *part);
+            let start = Instant::now();
+            let outcome = match &parsed {
+                Ok(parsed) => solve_part(solver.as_ref(), parsed.as_ref(), *part),
+                Err(message) => Outcome::Panicked(message.clone()),
+            };
+            debug!(
+                "day {} part {}: {outcome} in {:?}",
+                day.number,
+                part.number(),
+                start.elapsed()
+            );
+            results.push(PartResult {
+                year: day.year,
+                day: day.number,
+                part: *part,
+                outcome,
+                input_hash,
+                parse_time,
+                solve_time: start.elapsed(),
+            });
+        }
+    }
+    Report { results }
+}
+
+/// Solves a single part, turning a panic into an [`Outcome`] instead of aborting the whole run.
+pub fn solve_part(solver: &dyn Solver, parsed: &dyn Any, part: Part) -> Outcome {
+    match catch_panic(|| solver.solve(parsed, part)) {
+        Ok(answer) if !answer.is_solved() => Outcome::Unimplemented,
+        Ok(answer) => Outcome::Answer(answer),
+        Err(message) if message.starts_with("not yet implemented") => Outcome::Unimplemented,
+        Err(message) => Outcome::Panicked(message),
+    }
+}
+
+/// Number of [`catch_panic`] calls in progress, on any thread.
+static CATCHING: AtomicUsize = AtomicUsize::new(0);
+/// The latest panic seen by the hook: its message, and the message with its location.
+static LAST_PANIC: Mutex<Option<(String, String)>> = Mutex::new(None);
+static HOOK: OnceLock<()> = OnceLock::new();
+
+thread_local! {
+    /// Number of [`catch_panic`] calls in progress on this thread.
+    static CATCHING_HERE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The message a panic was raised with.
+fn panic_message(payload: &dyn Any) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "Box<dyn Any>".into(),
+        },
+    }
+}
+
+/// Installs, once per process, the panic hook that keeps panics caught by [`catch_panic`] quiet
+/// and remembers where they happened. Other panics go to the hook that was there before.
+fn install_panic_hook() {
+    HOOK.get_or_init(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let message = panic_message(info.payload());
+            let located = match info.location() {
+                Some(location) => format!("{message} ({location})"),
+                None => message.clone(),
+            };
+            *LAST_PANIC.lock().unwrap_or_else(|err| err.into_inner()) = Some((message, located));
+
+            // Solutions may panic inside rayon jobs, whose panics reach the caller's
+            // `catch_unwind` from a worker thread.
+            let caught = CATCHING_HERE.get() > 0
+                || (rayon::current_thread_index().is_some() && CATCHING.load(Ordering::SeqCst) > 0);
+            if !caught {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, returning the panic message and location if it panics.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    CATCHING.fetch_add(1, Ordering::SeqCst);
+    CATCHING_HERE.set(CATCHING_HERE.get() + 1);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_HERE.set(CATCHING_HERE.get() - 1);
+    CATCHING.fetch_sub(1, Ordering::SeqCst);
+
+    result.map_err(|payload| {
+        // The payload holds the message wherever the panic happened, the hook's record adds the
+        // location as long as no other panic came in between.
+        let message = panic_message(payload.as_ref());
+        match LAST_PANIC
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take()
+        {
+            Some((last, located)) if last == message => located,
+            _ => message,
+        }
+    })
+}
+
+/// Whether `part` of `day` is implemented, judged by running it on the day's example input.
+pub fn status(day: &Registration, part: Part) -> &'static str {
+    let Ok(input) = input::load(
+        day.year,
+        day.number,
+        &InputType::Example(None),
+        &InputSource::Default,
+    ) else {
+        return "unknown";
+    };
+    let solver = day.solver();
+    match catch_panic(|| solver.parse(&input)) {
+        Ok(Ok(parsed)) => match solve_part(solver.as_ref(), parsed.as_ref(), part) {
+            Outcome::Unimplemented => "todo",
+            _ => "implemented",
+        },
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    use super::*;
+
+    #[test]
+    fn catches_panics_with_their_location() {
+        let message = catch_panic(|| panic!("day {} broke", 3)).unwrap_err();
+        assert!(
+            message.starts_with("day 3 broke (src/runner.rs:"),
+            "{message}"
+        );
+        assert_eq!(catch_panic(|| 5), Ok(5));
+    }
+
+    #[test]
+    fn catches_panics_from_rayon_workers() {
+        let message = catch_panic(|| {
+            (0..64u32)
+                .into_par_iter()
+                .map(|n| if n == 40 { panic!("worker broke") } else { n })
+                .sum::<u32>()
+        })
+        .unwrap_err();
+        assert!(message.starts_with("worker broke"), "{message}");
+    }
+
+    #[test]
+    fn catches_panics_on_many_threads_at_once() {
+        let threads: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    for _ in 0..50 {
+                        let message = catch_panic(|| panic!("thread {i}")).unwrap_err();
+                        assert!(message.starts_with(&format!("thread {i}")), "{message}");
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::registry;
+
+/// Where the `yearYYYY` directories of new day modules are created.
+pub const DEFAULT_SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Creates `yearYYYY/dayN/` under `source_dir` with a stub module, an empty example input and an
+/// `example_answers` skeleton, returning the files it wrote. `build.rs` picks the directory up,
+/// so the day is registered on the next build, along with its year if it's the year's first day.
+/// Fails without writing anything if the directory already has any of these files, or the year
+/// has no such day.
+pub fn new_day(source_dir: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=registry::day_count(year)).contains(&day) {
+        return Err(format!(
+            "{year} only has {} days",
+            registry::day_count(year)
+        ));
+    }
+    let dir = source_dir
+        .join(format!("year{year}"))
+        .join(format!("day{day}"));
+    let files = [
+        (dir.join("mod.rs"), module(day, title)),
+        (dir.join("example_input"), String::new()),
+        (dir.join("example_answers"), "part1:\npart2:\n".to_string()),
+    ];
+
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(format!("{} already exists", existing.display()));
+    }
+
+    fs::create_dir_all(&dir).map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+    for (path, contents) in &files {
+        fs::write(path, contents)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn module(day: u8, title: &str) -> String {
+    format!(
+        r#"use crate::{{DayImpl, answer::Answer, parse::ParseError}};
+
+pub struct Day;
+
+register_day!({day}, {title:?});
+
+impl DayImpl for Day {{
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.into())
+    }}
+
+    fn part_one(_input: &Self::Input) -> Answer {{
+        todo!()
+    }}
+
+    fn part_two(_input: &Self::Input) -> Answer {{
+        todo!()
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn new_day_writes_the_module_and_example_files() {
+        let dir = temp_dir("new");
+
+        let written = new_day(&dir, 2025, 3, "Lobby").unwrap();
+
+        let day = dir.join("year2025").join("day3");
+        assert_eq!(
+            written,
+            [
+                day.join("mod.rs"),
+                day.join("example_input"),
+                day.join("example_answers")
+            ]
+        );
+        let module = fs::read_to_string(day.join("mod.rs")).unwrap();
+        assert!(module.contains(r#"register_day!(3, "Lobby");"#));
+        assert_eq!(fs::read_to_string(day.join("example_input")).unwrap(), "");
+        assert_eq!(
+            fs::read_to_string(day.join("example_answers")).unwrap(),
+            "part1:\npart2:\n"
+        );
+    }
+
+    #[test]
+    fn new_day_leaves_existing_days_alone() {
+        let dir = temp_dir("existing");
+        let day = dir.join("year2025").join("day3");
+        new_day(&dir, 2025, 3, "Lobby").unwrap();
+        fs::write(day.join("example_input"), "1 2 3\n").unwrap();
+        fs::remove_file(day.join("example_answers")).unwrap();
+
+        let err = new_day(&dir, 2025, 3, "Renamed").unwrap_err();
+
+        assert!(err.ends_with("mod.rs already exists"), "{err}");
+        let module = fs::read_to_string(day.join("mod.rs")).unwrap();
+        assert!(module.contains(r#"register_day!(3, "Lobby");"#));
+        assert_eq!(
+            fs::read_to_string(day.join("example_input")).unwrap(),
+            "1 2 3\n"
+        );
+        assert!(!day.join("example_answers").exists());
+    }
+
+    #[test]
+    fn new_day_rejects_days_past_the_end_of_the_year() {
+        let dir = temp_dir("late");
+
+        assert_eq!(
+            new_day(&dir, 2025, 13, "Late").unwrap_err(),
+            "2025 only has 12 days"
+        );
+        assert!(!dir.join("year2025").exists());
+        assert!(new_day(&dir, 2024, 13, "Later").is_ok());
+    }
+}
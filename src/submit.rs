@@ -0,0 +1,343 @@
+//! Submitting answers, and remembering what the website said about them.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, client::Client};
+
+/// Where the submissions of `year` are remembered when no other location is given.
+pub fn default_history_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("submissions")
+        .join(format!("{year}.json"))
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous answer, so it wasn't checked.
+    Wait {
+        seconds: u64,
+    },
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+impl Response {
+    /// Classifies the page returned after posting an answer.
+    pub fn classify(page: &str) -> Result<Self, String> {
+        let response = if page.contains("That's the right answer") {
+            Response::Correct
+        } else if page.contains("your answer is too high") {
+            Response::TooHigh
+        } else if page.contains("your answer is too low") {
+            Response::TooLow
+        } else if page.contains("That's not the right answer") {
+            Response::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Response::Wait {
+                seconds: wait_time(page).as_secs(),
+            }
+        } else if page.contains("Did you already complete it") {
+            Response::AlreadySolved
+        } else {
+            return Err(format!("unrecognised response:\n{}", page.trim()));
+        };
+        Ok(response)
+    }
+
+    /// Whether the answer was checked and found wrong.
+    fn is_wrong(&self) -> bool {
+        matches!(self, Response::TooHigh | Response::TooLow | Response::Wrong)
+    }
+}
+
+/// Parses the `You have 1m 23s left to wait` part of a too-recently page.
+fn wait_time(page: &str) -> Duration {
+    let Some(start) = page.find("You have ") else {
+        return Duration::ZERO;
+    };
+    let rest = &page[start + "You have ".len()..];
+    let rest = rest.split(" left").next().unwrap_or_default();
+
+    let seconds = rest
+        .split_whitespace()
+        .filter_map(|amount| {
+            let unit = amount.chars().last()?;
+            let value: u64 = amount[..amount.len() - 1].parse().ok()?;
+            match unit {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                's' => Some(value),
+                _ => None,
+            }
+        })
+        .sum();
+    Duration::from_secs(seconds)
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "too high"),
+            Response::TooLow => write!(f, "too low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::Wait { seconds } => {
+                write!(f, "not checked, wait {seconds}s before submitting again")
+            }
+            Response::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: Answer,
+    pub response: Response,
+}
+
+/// Every answer submitted so far, keyed by day, then part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(BTreeMap<u8, BTreeMap<u8, Vec<Submission>>>);
+
+impl History {
+    /// Loads the history at `path`, or an empty one if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|err| format!("invalid submissions file {}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).expect("history is serializable");
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+        }
+        std::fs::write(path, contents + "\n")
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))
+    }
+
+    fn submissions(&self, day: u8, part: u8) -> &[Submission] {
+        self.0
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Explains why `answer` can't be right, judging by earlier responses.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Result<(), String> {
+        let submissions = self.submissions(day, part);
+        if let Some(correct) = submissions
+            .iter()
+            .find(|submission| submission.response == Response::Correct)
+        {
+            return Err(format!("already solved, the answer was {}", correct.answer));
+        }
+        let value = numeric_value(answer);
+        if let Some(wrong) = submissions.iter().find(|submission| {
+            submission.response.is_wrong()
+                && (submission.answer == *answer
+                    || value.is_some() && numeric_value(&submission.answer) == value)
+        }) {
+            return Err(format!(
+                "{answer} was already submitted: {}",
+                wrong.response
+            ));
+        }
+
+        let Some(value) = value else {
+            return Ok(());
+        };
+        for submission in submissions {
+            let Some(previous) = numeric_value(&submission.answer) else {
+                continue;
+            };
+            match submission.response {
+                Response::TooHigh if value >= previous => {
+                    return Err(format!("{answer} is too high, {previous} already was"));
+                }
+                Response::TooLow if value <= previous => {
+                    return Err(format!("{answer} is too low, {previous} already was"));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: Answer, response: Response) {
+        self.0
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .or_default()
+            .push(Submission { answer, response });
+    }
+}
+
+/// The value of a numeric answer, including text holding a number, like answers submitted from
+/// the command line by older versions.
+fn numeric_value(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Text(text) => text.parse::<Answer>().ok()?.as_i128(),
+        answer => answer.as_i128(),
+    }
+}
+
+/// Submits `answer` unless earlier responses already rule it out, recording the response.
+/// `history` must be the one of `year`.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<Response, String> {
+    if !answer.is_solved() {
+        return Err("there is no answer to submit".into());
+    }
+    history.check(day, part, answer)?;
+
+    let page = client.submit(year, day, part, answer)?;
+    let response = Response::classify(&page)?;
+    history.record(day, part, answer.clone(), response);
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer to \
+        decorating the North Pole.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  \
+        Please wait one minute before trying again.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure \
+        you're using the full input data.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+    const ALREADY_SOLVED: &str = "<article><p>You don't seem to be solving the right level.  \
+        Did you already complete it?</p></article>";
+
+    fn client(server: &MockServer) -> Client {
+        let throttle_file = std::env::temp_dir().join(format!(
+            "aoc-submit-last-request-{}-{}",
+            std::process::id(),
+            server.base_url.rsplit(':').next().unwrap()
+        ));
+        Client::new(
+            &server.base_url,
+            "abc123".into(),
+            Duration::ZERO,
+            throttle_file,
+        )
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(Response::classify(CORRECT), Ok(Response::Correct));
+        assert_eq!(Response::classify(TOO_HIGH), Ok(Response::TooHigh));
+        assert_eq!(Response::classify(TOO_LOW), Ok(Response::TooLow));
+        assert_eq!(Response::classify(WRONG), Ok(Response::Wrong));
+        assert_eq!(Response::classify(WAIT), Ok(Response::Wait { seconds: 83 }));
+        assert_eq!(
+            Response::classify(ALREADY_SOLVED),
+            Ok(Response::AlreadySolved)
+        );
+        assert!(Response::classify("<html>Maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn posts_level_and_answer() {
+        let server = MockServer::start(vec![(200, TOO_LOW)]);
+        let mut history = History::default();
+
+        let response = submit(
+            &client(&server),
+            &mut history,
+            2025,
+            4,
+            2,
+            &Answer::from(1234),
+        )
+        .unwrap();
+
+        assert_eq!(response, Response::TooLow);
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/4/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+        assert_eq!(history.submissions(4, 2).len(), 1);
+    }
+
+    #[test]
+    fn refuses_answers_ruled_out_by_history() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, CORRECT)]);
+        let client = client(&server);
+        let mut history = History::default();
+
+        submit(&client, &mut history, 2025, 1, 1, &Answer::from(100)).unwrap();
+        submit(&client, &mut history, 2025, 1, 1, &Answer::from(50)).unwrap();
+
+        for answer in [100, 50, 150, 20] {
+            assert!(submit(&client, &mut history, 2025, 1, 1, &Answer::from(answer)).is_err());
+        }
+        assert_eq!(server.requests().len(), 2);
+
+        let response = submit(&client, &mut history, 2025, 1, 1, &Answer::from(75)).unwrap();
+        assert_eq!(response, Response::Correct);
+        assert!(submit(&client, &mut history, 2025, 1, 1, &Answer::from(76)).is_err());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn numeric_text_counts_as_a_number() {
+        let mut history = History::default();
+        history.record(1, 1, Answer::Text("100".into()), Response::TooHigh);
+        history.record(1, 1, Answer::Text(" 20".into()), Response::TooLow);
+
+        for answer in [100, 150, 20, 5] {
+            assert!(history.check(1, 1, &Answer::from(answer)).is_err());
+        }
+        assert!(history.check(1, 1, &Answer::Text("100".into())).is_err());
+        assert_eq!(history.check(1, 1, &Answer::from(50)), Ok(()));
+    }
+
+    #[test]
+    fn waiting_does_not_rule_out_the_answer() {
+        let server = MockServer::start(vec![(200, WAIT), (200, WRONG)]);
+        let client = client(&server);
+        let mut history = History::default();
+
+        let answer = Answer::from("abc");
+        submit(&client, &mut history, 2025, 2, 1, &answer).unwrap();
+        assert_eq!(
+            submit(&client, &mut history, 2025, 2, 1, &answer),
+            Ok(Response::Wrong)
+        );
+        assert!(submit(&client, &mut history, 2025, 2, 1, &answer).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Frames showing a simulation's progress, for watching a day work through its input with
+//! `--trace`. Days emit frames with [`frame`], which only builds them while tracing is on, so
+//! solutions can trace freely without slowing down normal runs and benchmarks.
+
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, IsTerminal, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use crate::Part;
+
+/// Where frames go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceTarget {
+    /// Printed to stderr as they come.
+    Terminal,
+    /// Printed to stderr, redrawing the screen and waiting for Enter after each frame.
+    Step,
+    /// Written to numbered files in a directory, one per frame.
+    Dir(PathBuf),
+}
+
+impl FromStr for TraceTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "terminal" => Ok(TraceTarget::Terminal),
+            None if s == "step" => Ok(TraceTarget::Step),
+            Some(("dir", path)) if !path.is_empty() => Ok(TraceTarget::Dir(path.into())),
+            _ => Err(format!(
+                "Invalid trace target: {s} (expected terminal, step or dir:<path>)"
+            )),
+        }
+    }
+}
+
+impl Display for TraceTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceTarget::Terminal => write!(f, "terminal"),
+            TraceTarget::Step => write!(f, "step"),
+            TraceTarget::Dir(path) => write!(f, "dir:{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi(&self) -> &'static str {
+        match self {
+            Colour::Red => "\x1b[31m",
+            Colour::Green => "\x1b[32m",
+            Colour::Yellow => "\x1b[33m",
+            Colour::Blue => "\x1b[34m",
+            Colour::Magenta => "\x1b[35m",
+            Colour::Cyan => "\x1b[36m",
+        }
+    }
+}
+
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// One snapshot of a simulation: a title and a text drawing, with characters of the drawing
+/// coloured on terminals that support it.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub title: String,
+    pub text: String,
+    colours: Vec<(char, Colour)>,
+}
+
+impl Frame {
+    pub fn new(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Frame {
+            title: title.into(),
+            text: text.into(),
+            colours: vec![],
+        }
+    }
+
+    /// Draws every `c` in the text in `colour`.
+    pub fn colour(mut self, c: char, colour: Colour) -> Self {
+        self.colours.push((c, colour));
+        self
+    }
+
+    fn render(&self, context: &str, ansi: bool) -> String {
+        let mut rendered = String::new();
+        if ansi {
+            rendered += &format!("{BOLD}{context}{}{RESET}\n", self.title);
+        } else {
+            rendered += &format!("{context}{}\n", self.title);
+        }
+        for c in self.text.chars() {
+            match self.colours.iter().find(|(coloured, _)| *coloured == c) {
+                Some((_, colour)) if ansi => {
+                    rendered += colour.ansi();
+                    rendered.push(c);
+                    rendered += RESET;
+                }
+                _ => rendered.push(c),
+            }
+        }
+        if !rendered.ends_with('\n') {
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+
+struct Tracer {
+    target: TraceTarget,
+    ansi: bool,
+    part: Option<(u8, Part)>,
+    frames: usize,
+}
+
+/// Turns tracing on, sending every following frame to `target`.
+pub fn start(target: TraceTarget) -> io::Result<()> {
+    if let TraceTarget::Dir(dir) = &target {
+        fs::create_dir_all(dir)?;
+    }
+    let ansi = !matches!(target, TraceTarget::Dir(_))
+        && io::stderr().is_terminal()
+        && std::env::var_os("NO_COLOR").is_none();
+    *TRACER.lock().unwrap_or_else(|err| err.into_inner()) = Some(Tracer {
+        target,
+        ansi,
+        part: None,
+        frames: 0,
+    });
+    ENABLED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Labels the following frames as coming from `part` of `day`, numbering them from 1 again.
+pub fn set_part(day: u8, part: Part) {
+    if !enabled() {
+        return;
+    }
+    if let Some(tracer) = TRACER
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .as_mut()
+    {
+        tracer.part = Some((day, part));
+        tracer.frames = 0;
+    }
+}
+
+/// Emits the frame built by `build`, which is only called while tracing is on.
+#[inline]
+pub fn frame(build: impl FnOnce() -> Frame) {
+    if enabled() {
+        emit(build());
+    }
+}
+
+fn emit(frame: Frame) {
+    let mut tracer = TRACER.lock().unwrap_or_else(|err| err.into_inner());
+    let Some(tracer) = tracer.as_mut() else {
+        return;
+    };
+    tracer.frames += 1;
+    let context = match tracer.part {
+        Some((day, part)) => format!("Day {day} part {}, ", part.number()),
+        None => String::new(),
+    };
+
+    // A failing trace shouldn't fail the solution, so errors are reported and tracing goes on.
+    let result = match &tracer.target {
+        TraceTarget::Terminal => {
+            io::stderr().write_all(frame.render(&context, tracer.ansi).as_bytes())
+        }
+        TraceTarget::Step => step(&frame.render(&context, tracer.ansi), tracer.ansi),
+        TraceTarget::Dir(dir) => {
+            let name = match tracer.part {
+                Some((day, part)) => {
+                    format!("day{day:02}-part{}-{:05}.txt", part.number(), tracer.frames)
+                }
+                None => format!("frame-{:05}.txt", tracer.frames),
+            };
+            fs::write(dir.join(name), frame.render(&context, false))
+        }
+    };
+    if let Err(err) = result {
+        warn!("failed to write a trace frame: {err}");
+    }
+}
+
+/// Shows `rendered` alone on the screen and waits for Enter, read from the terminal so that
+/// stepping works while the input comes from stdin.
+fn step(rendered: &str, ansi: bool) -> io::Result<()> {
+    let mut stderr = io::stderr();
+    if ansi {
+        stderr.write_all(CLEAR_SCREEN.as_bytes())?;
+    }
+    write!(stderr, "{rendered}[Enter] next frame ")?;
+    stderr.flush()?;
+
+    let mut line = String::new();
+    match fs::File::open("/dev/tty") {
+        Ok(tty) => BufReader::new(tty).read_line(&mut line)?,
+        Err(_) => io::stdin().lock().read_line(&mut line)?,
+    };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_targets() {
+        assert_eq!("terminal".parse(), Ok(TraceTarget::Terminal));
+        assert_eq!("step".parse(), Ok(TraceTarget::Step));
+        assert_eq!("dir:frames".parse(), Ok(TraceTarget::Dir("frames".into())));
+        assert!("dir:".parse::<TraceTarget>().is_err());
+        assert!("frames".parse::<TraceTarget>().is_err());
+    }
+
+    #[test]
+    fn colours_only_with_ansi() {
+        let frame = Frame::new("row 1", ".|.").colour('|', Colour::Cyan);
+        assert_eq!(frame.render("", false), "row 1\n.|.\n");
+        assert_eq!(
+            frame.render("Day 7 part 1, ", true),
+            "\x1b[1mDay 7 part 1, row 1\x1b[0m\n.\x1b[36m|\x1b[0m.\n"
+        );
+    }
+}
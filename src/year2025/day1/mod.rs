@@ -0,0 +1,148 @@
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    parse::{ParseError, Span},
+    trace::{self, Colour, Frame},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(1, "Secret Entrance", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 4000,
+    generate,
+};
+
+/// `size` rotations of 1 to 999 clicks in either direction.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}\n", rng.range(1..=999))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub degrees: i16,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// The dial as a row of its 100 positions, with 0 marked `|` and the pointer `o`, or `@` when
+/// it points at 0.
+fn draw(rotation: &Rotation, pointing_at: i16, zero_count: i16) -> Frame {
+    let pointing_at = pointing_at.rem_euclid(100) as usize;
+    let dial: String = (0..100)
+        .map(|position| match position {
+            0 if pointing_at == 0 => '@',
+            _ if position == pointing_at => 'o',
+            0 => '|',
+            _ => '.',
+        })
+        .collect();
+    let direction = match rotation.direction {
+        Direction::Left => 'L',
+        Direction::Right => 'R',
+    };
+    let title = format!(
+        "{direction}{} to {pointing_at}, {zero_count} zeros",
+        rotation.degrees
+    );
+    Frame::new(title, dial)
+        .colour('o', Colour::Green)
+        .colour('@', Colour::Red)
+        .colour('|', Colour::Yellow)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    Span::new(input).lines_of(|line| {
+        let Some((direction, degrees)) = line.split_first() else {
+            return Err(line.error("Expected a rotation like L68"));
+        };
+        let direction = match direction.as_str() {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            other => {
+                return Err(direction.error(format!("Invalid direction character: {other}")));
+            }
+        };
+        Ok(Rotation {
+            direction,
+            degrees: degrees.int()?,
+        })
+    })
+}
+
+impl DayImpl for Day {
+    type Input = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut pointing_at = 50;
+        let mut zero_count = 0;
+
+        for rotation in input {
+            let Rotation { degrees, direction } = rotation;
+            pointing_at = match direction {
+                Direction::Left => pointing_at - degrees,
+                Direction::Right => pointing_at + degrees,
+            } % 100;
+
+            if pointing_at == 0 {
+                zero_count += 1;
+            }
+            trace::frame(|| draw(rotation, pointing_at, zero_count));
+        }
+
+        zero_count.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut pointing_at = 50;
+        let mut zero_count = 0;
+
+        for rotation in input {
+            let Rotation { degrees, direction } = rotation;
+            let full_rotations = degrees / 100;
+            zero_count += full_rotations;
+
+            let degrees = degrees.rem_euclid(100);
+            let old_pointing_at = pointing_at;
+            pointing_at = match direction {
+                Direction::Left => pointing_at - degrees,
+                Direction::Right => pointing_at + degrees,
+            };
+            if (pointing_at < 0 && old_pointing_at != 0) || pointing_at > 100 {
+                zero_count += 1;
+            }
+            pointing_at = pointing_at.rem_euclid(100);
+
+            if pointing_at == 0 {
+                zero_count += 1;
+            }
+            trace::frame(|| draw(rotation, pointing_at, zero_count));
+        }
+
+        zero_count.into()
+    }
+}
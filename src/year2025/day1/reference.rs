@@ -0,0 +1,31 @@
+//! Turns the dial one click at a time.
+
+use crate::answer::Answer;
+
+/// How often the dial ends a rotation on zero, and how often any click lands on it.
+fn simulate(input: &str) -> (usize, usize) {
+    let mut dial = 50;
+    let (mut ends_on_zero, mut clicks_on_zero) = (0, 0);
+    for line in input.lines() {
+        let (direction, clicks) = line.split_at(1);
+        let step = if direction == "L" { -1 } else { 1 };
+        for _ in 0..clicks.parse::<u32>().unwrap() {
+            dial = (dial + step + 100) % 100;
+            if dial == 0 {
+                clicks_on_zero += 1;
+            }
+        }
+        if dial == 0 {
+            ends_on_zero += 1;
+        }
+    }
+    (ends_on_zero, clicks_on_zero)
+}
+
+pub fn part_one(input: &str) -> Answer {
+    simulate(input).0.into()
+}
+
+pub fn part_two(input: &str) -> Answer {
+    simulate(input).1.into()
+}
@@ -0,0 +1,21 @@
+use crate::{DayImpl, answer::Answer, parse::ParseError};
+
+pub struct Day;
+
+register_day!(10, "Factory");
+
+impl DayImpl for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.into())
+    }
+
+    fn part_one(_input: &Self::Input) -> Answer {
+        todo!()
+    }
+
+    fn part_two(_input: &Self::Input) -> Answer {
+        todo!()
+    }
+}
@@ -0,0 +1,134 @@
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    interval::Interval,
+    parse::{ParseError, Span},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(2, "Gift Shop", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 35,
+    generate,
+};
+
+/// `size` comma-separated ranges of 1 to 10 digit IDs. Ranges hold up to `80 * size²` IDs, about
+/// 100,000 at the default size, so small sizes stay quick to check by brute force.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let first = rng.digits(digits);
+            let last = first + rng.range(0..=80 * (size * size) as u64);
+            format!("{first}-{last}")
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+/// Parses `range` as `first-last`.
+fn parse_range(range: Span) -> Result<Interval, ParseError> {
+    let (first, last) = range.split_once("-", "Invalid range, expected first-last")?;
+    let (first, last) = (first.int()?, last.int()?);
+    if last < first {
+        return Err(range.error("Range ends before it starts"));
+    }
+    if last == u64::MAX {
+        return Err(range.error(format!("Range must end below {}", u64::MAX)));
+    }
+    Ok(Interval::inclusive(first, last))
+}
+
+/// Every range, in order. Ranges are kept apart, so an ID in two of them is counted twice.
+fn parse_input(input: &str) -> Result<Vec<Interval>, ParseError> {
+    Span::new(input).trim().list(",", parse_range)
+}
+
+impl DayImpl for Day {
+    type Input = Vec<Interval>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        fn is_valid(id: String) -> bool {
+            if id.starts_with('0') {
+                return false;
+            }
+
+            if !id.len().is_multiple_of(2) {
+                return true;
+            }
+
+            let midpoint = id.len() / 2;
+            let (first_half, last_half) = id.split_at(midpoint);
+            first_half != last_half
+        }
+
+        let sum: u64 = input
+            .par_iter()
+            .map(|range| {
+                range
+                    .values()
+                    .filter(|id| !is_valid(format!("{}", id)))
+                    .sum::<u64>()
+            })
+            .sum();
+
+        sum.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        fn segments_of_len(id: &str, segment_len: usize) -> Vec<String> {
+            assert_eq!(id.len() % segment_len, 0);
+            let n = id.len() / segment_len;
+            (0..n)
+                .map(|i| id[(segment_len * i)..(segment_len * (i + 1))].into())
+                .collect()
+        }
+
+        fn is_valid(id: String) -> bool {
+            if id.starts_with('0') {
+                return false;
+            }
+
+            let len = id.len();
+            let len_divisors: Vec<usize> = (1..=(len / 2))
+                .filter(|divisor| len.is_multiple_of(*divisor))
+                .collect();
+            len_divisors
+                .iter()
+                .filter(|segment_len| {
+                    let segments = segments_of_len(&id, **segment_len);
+                    segments.iter().all(|segment| segment == &segments[0])
+                })
+                .count()
+                == 0
+        }
+
+        let sum: u64 = input
+            .par_iter()
+            .map(|range| {
+                range
+                    .values()
+                    .filter(|id| !is_valid(format!("{}", id)))
+                    .sum::<u64>()
+            })
+            .sum();
+        sum.into()
+    }
+}
@@ -0,0 +1,46 @@
+//! Checks every ID of every range arithmetically: an ID made of a block of digits repeated is a
+//! multiple of a number like 1001 or 10101, such as 123123 = 123 × 1001.
+
+use crate::answer::Answer;
+
+fn parse(input: &str) -> Vec<(u64, u64)> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (first, last) = range.split_once('-').unwrap();
+            (first.parse().unwrap(), last.parse().unwrap())
+        })
+        .collect()
+}
+
+/// Whether `id` is some block of digits repeated a number of times that `repeats` accepts.
+fn is_repeated(id: u64, repeats: impl Fn(u32) -> bool) -> bool {
+    let digits = id.to_string().len() as u32;
+    (1..digits)
+        .filter(|block| digits.is_multiple_of(*block) && repeats(digits / block))
+        .any(|block| {
+            let multiplier: u64 = (0..digits / block).map(|i| 10u64.pow(block * i)).sum();
+            id.is_multiple_of(multiplier)
+        })
+}
+
+fn total(input: &str, repeats: impl Fn(u32) -> bool) -> Answer {
+    let mut total = 0;
+    for (first, last) in parse(input) {
+        for id in first..=last {
+            if is_repeated(id, &repeats) {
+                total += id;
+            }
+        }
+    }
+    total.into()
+}
+
+pub fn part_one(input: &str) -> Answer {
+    total(input, |repeats| repeats == 2)
+}
+
+pub fn part_two(input: &str) -> Answer {
+    total(input, |repeats| repeats >= 2)
+}
@@ -0,0 +1,119 @@
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    parse::{ParseError, Span},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(3, "Lobby", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 200,
+    generate,
+};
+
+/// `size` banks of 100 batteries with joltages 1 to 9.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let bank: String = (0..100)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect();
+            bank + "\n"
+        })
+        .collect()
+}
+
+pub struct Battery(pub Vec<u8>);
+
+impl Battery {
+    /// Parses `line` as a row of joltage digits.
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        if line.is_empty() {
+            return Err(line.error("Empty battery bank"));
+        }
+        line.chars()
+            .map(|(c, at)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(at.error(format!("Invalid joltage: {c}"))),
+            })
+            .collect::<Result<_, _>>()
+            .map(Battery)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Battery>, ParseError> {
+    Span::new(input).lines_of(Battery::parse)
+}
+
+impl DayImpl for Day {
+    type Input = Vec<Battery>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let result: usize = input
+            .iter()
+            .map(|Battery(digits)| {
+                let mut max = *digits.iter().max().unwrap();
+                let first_max_pos = loop {
+                    // Get first occurrence of max. If it has no digit to its right then decrease max and try again
+                    match digits.iter().position(|digit| digit == &max) {
+                        Some(pos) if pos == digits.len() - 1 => {
+                            max -= 1;
+                            continue;
+                        }
+                        Some(m) => break m,
+                        None => {
+                            max -= 1; // ex. We decreased from 9 to 8, but 8 isnt in the battery, try 7
+                        }
+                    };
+                };
+
+                let remaining = &digits[(first_max_pos + 1)..];
+                let remaining_max = remaining.iter().max().unwrap();
+                (max as usize) * 10 + (*remaining_max as usize)
+            })
+            .sum();
+        result.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        fn solve_segment(digits: &[u8], n: usize) -> usize {
+            if n == 0 {
+                return 0;
+            }
+            let mut max = *digits.iter().max().unwrap();
+            let len = digits.len();
+
+            let first_max_pos = loop {
+                match digits.iter().position(|digit| digit == &max) {
+                    None => max -= 1,
+                    Some(pos) if len - pos < n => max -= 1,
+                    Some(m) => break m,
+                };
+            };
+
+            10_usize.pow((n - 1) as u32) * (max as usize)
+                + solve_segment(&digits[first_max_pos + 1..], n - 1)
+        }
+
+        let result: usize = input
+            .iter()
+            .map(|Battery(digits)| solve_segment(digits, 12))
+            .sum();
+        result.into()
+    }
+}
@@ -0,0 +1,48 @@
+//! Tries every choice of batteries, remembering the best pick from each position.
+
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+
+/// The largest joltage made of `count` of the batteries from `from` onwards, if there are enough.
+fn best(
+    bank: &[u64],
+    from: usize,
+    count: usize,
+    memo: &mut HashMap<(usize, usize), Option<u64>>,
+) -> Option<u64> {
+    if count == 0 {
+        return Some(0);
+    }
+    if bank.len() - from < count {
+        return None;
+    }
+    if let Some(best) = memo.get(&(from, count)) {
+        return *best;
+    }
+    let take = best(bank, from + 1, count - 1, memo)
+        .map(|rest| bank[from] * 10u64.pow(count as u32 - 1) + rest);
+    let skip = best(bank, from + 1, count, memo);
+    let result = take.max(skip);
+    memo.insert((from, count), result);
+    result
+}
+
+fn total(input: &str, count: usize) -> Answer {
+    let total: u64 = input
+        .lines()
+        .map(|line| {
+            let bank: Vec<u64> = line.chars().map(|c| c.to_digit(10).unwrap().into()).collect();
+            best(&bank, 0, count, &mut HashMap::new()).unwrap()
+        })
+        .sum();
+    total.into()
+}
+
+pub fn part_one(input: &str) -> Answer {
+    total(input, 2)
+}
+
+pub fn part_two(input: &str) -> Answer {
+    total(input, 12)
+}
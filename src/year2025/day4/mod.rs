@@ -0,0 +1,124 @@
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    grid::{Grid, Point},
+    parse::ParseError,
+    trace::{self, Colour, Frame},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(4, "Printing Department", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 140,
+    generate,
+};
+
+/// A `size` by `size` grid, with paper on about two thirds of the cells.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| if rng.chance(0.65) { '@' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Paper,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell::Empty),
+            '@' => Ok(Cell::Paper),
+            _ => Err(format!("Invalid cell value: {value}")),
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Empty => '.',
+            Cell::Paper => '@',
+        }
+    }
+}
+
+/// Rolls of paper with fewer than four rolls around them, which a forklift can reach.
+fn accessible(grid: &Grid<Cell>) -> Vec<Point> {
+    grid.find_all(|cell| *cell == Cell::Paper)
+        .filter(|point| {
+            grid.neighbours8(*point)
+                .filter(|neighbour| grid[*neighbour] == Cell::Paper)
+                .count()
+                < 4
+        })
+        .collect()
+}
+
+/// `grid` with the rolls about to be removed drawn as `x`.
+fn draw(grid: &Grid<Cell>, removing: &[Point]) -> String {
+    let mut grid = grid.map(|cell| char::from(*cell));
+    for point in removing {
+        grid[*point] = 'x';
+    }
+    grid.to_string()
+}
+
+impl DayImpl for Day {
+    type Input = Grid<Cell>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> Answer {
+        accessible(grid).len().into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let mut grid = input.clone();
+        let mut removed_count = 0;
+        let mut waves = 0;
+
+        loop {
+            let remove_positions = accessible(&grid);
+            if remove_positions.is_empty() {
+                break;
+            }
+            trace::frame(|| {
+                let title = format!(
+                    "removing {} rolls, {removed_count} removed so far",
+                    remove_positions.len()
+                );
+                Frame::new(title, draw(&grid, &remove_positions)).colour('x', Colour::Red)
+            });
+            removed_count += remove_positions.len();
+            waves += 1;
+            for point in remove_positions {
+                grid[point] = Cell::Empty
+            }
+        }
+        debug!("removed {removed_count} rolls in {waves} waves");
+        removed_count.into()
+    }
+}
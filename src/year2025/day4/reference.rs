@@ -0,0 +1,60 @@
+//! Counts each roll's neighbours directly on a grid of characters, removing rolls until none can
+//! be reached.
+
+use crate::answer::Answer;
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect()
+}
+
+/// The rolls with fewer than four rolls in the eight cells around them.
+fn reachable(grid: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let mut reachable = vec![];
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if grid[y][x] != '@' {
+                continue;
+            }
+            let mut rolls = 0;
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let neighbour = y
+                        .checked_add_signed(dy)
+                        .and_then(|ny| grid.get(ny))
+                        .and_then(|row| row.get(x.checked_add_signed(dx)?));
+                    if (dx, dy) != (0, 0) && neighbour == Some(&'@') {
+                        rolls += 1;
+                    }
+                }
+            }
+            if rolls < 4 {
+                reachable.push((x, y));
+            }
+        }
+    }
+    reachable
+}
+
+pub fn part_one(input: &str) -> Answer {
+    reachable(&parse(input)).len().into()
+}
+
+pub fn part_two(input: &str) -> Answer {
+    let mut grid = parse(input);
+    let mut removed = 0;
+    loop {
+        let rolls = reachable(&grid);
+        if rolls.is_empty() {
+            break;
+        }
+        removed += rolls.len();
+        for (x, y) in rolls {
+            grid[y][x] = '.';
+        }
+    }
+    removed.into()
+}
@@ -0,0 +1,86 @@
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    interval::{Interval, IntervalSet},
+    parse::{ParseError, Span},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(5, "Cafeteria", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 180,
+    generate,
+};
+
+/// `size` possibly overlapping ID ranges, then five times as many IDs, all below 10^15.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_ID: u64 = 1_000_000_000_000_000;
+    let mut input = String::new();
+    for _ in 0..size {
+        let low = rng.range(1..=MAX_ID - 1);
+        let high = (low + rng.range(0..=MAX_ID / 1000)).min(MAX_ID);
+        input += &format!("{low}-{high}\n");
+    }
+    input += "\n";
+    for _ in 0..size * 5 {
+        input += &format!("{}\n", rng.range(1..=MAX_ID));
+    }
+    input
+}
+
+/// The fresh ingredient IDs, merged from the ranges, followed by the available ingredient IDs.
+pub type Database = (IntervalSet, Vec<u64>);
+
+fn parse_input(input: &str) -> Result<Database, ParseError> {
+    let [ranges, ids] = Span::new(input).sections()?;
+    let ranges = ranges.lines_of(|line| {
+        let line = line.trim();
+        let (lower, higher) = line.split_once("-", "Invalid range, expected low-high")?;
+        let (lower, higher) = (lower.int()?, higher.int()?);
+        if higher < lower {
+            return Err(line.error("Range ends before it starts"));
+        }
+        if higher == u64::MAX {
+            return Err(line.error(format!("Range must end below {}", u64::MAX)));
+        }
+        Ok(Interval::inclusive(lower, higher))
+    })?;
+    let fresh: IntervalSet = ranges.iter().copied().collect();
+    debug!(
+        "merged {} ranges into {}",
+        ranges.len(),
+        fresh.intervals().len()
+    );
+    let ids = ids.lines_of(|line| line.trim().int())?;
+    Ok((fresh, ids))
+}
+
+impl DayImpl for Day {
+    type Input = Database;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (fresh, ids) = input;
+        let result = ids.iter().filter(|id| fresh.contains(**id)).count();
+        result.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let (fresh, _) = input;
+        fresh.len().into()
+    }
+}
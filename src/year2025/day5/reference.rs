@@ -0,0 +1,53 @@
+//! Marks which stretches between range boundaries are fresh, then counts the marked IDs.
+
+use crate::answer::Answer;
+
+fn parse(input: &str) -> (Vec<(u64, u64)>, Vec<u64>) {
+    let (ranges, ids) = input.split_once("\n\n").unwrap();
+    let ranges = ranges
+        .lines()
+        .map(|line| {
+            let (low, high) = line.split_once('-').unwrap();
+            (low.parse().unwrap(), high.parse().unwrap())
+        })
+        .collect();
+    let ids = ids.lines().map(|id| id.parse().unwrap()).collect();
+    (ranges, ids)
+}
+
+pub fn part_one(input: &str) -> Answer {
+    let (ranges, ids) = parse(input);
+    ids.iter()
+        .filter(|id| ranges.iter().any(|(low, high)| low <= id && *id <= high))
+        .count()
+        .into()
+}
+
+/// IDs are too large for a bitmap of every ID, so the bitmap has one bit per stretch between
+/// consecutive range boundaries. Each stretch is either entirely inside a range or entirely
+/// outside all of them.
+pub fn part_two(input: &str) -> Answer {
+    let (ranges, _) = parse(input);
+    let mut boundaries: Vec<u64> = ranges
+        .iter()
+        .flat_map(|(low, high)| [*low, high + 1])
+        .collect();
+    boundaries.sort();
+    boundaries.dedup();
+
+    let mut fresh = vec![false; boundaries.len()];
+    for (low, high) in &ranges {
+        for (i, start) in boundaries.iter().enumerate() {
+            if low <= start && *start <= *high {
+                fresh[i] = true;
+            }
+        }
+    }
+    let count: u64 = boundaries
+        .windows(2)
+        .zip(&fresh)
+        .filter(|(_, fresh)| **fresh)
+        .map(|(stretch, _)| stretch[1] - stretch[0])
+        .sum();
+    count.into()
+}
@@ -0,0 +1,174 @@
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    parse::{ParseError, Span},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(6, "Trash Compactor", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 1000,
+    generate,
+};
+
+/// `size` problems of four numbers, each up to three digits and aligned left or right within
+/// its problem.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    const ROWS: usize = 4;
+    let mut lines = vec![String::new(); ROWS + 1];
+    for problem in 0..size {
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+        let width = rng.range(1..=3) as usize;
+        // One number spans the whole width, so no column of the problem is empty.
+        let full_row = rng.range(0..=ROWS as u64 - 1) as usize;
+        let left_aligned = rng.chance(0.5);
+        for (row, line) in lines[..ROWS].iter_mut().enumerate() {
+            let digits = match row == full_row {
+                true => width,
+                false => rng.range(1..=width as u64) as usize,
+            };
+            let number = rng.digits(digits as u32);
+            match left_aligned {
+                true => line.push_str(&format!("{number:<width$}")),
+                false => line.push_str(&format!("{number:>width$}")),
+            }
+        }
+        let operation = if rng.chance(0.5) { '*' } else { '+' };
+        lines[ROWS].push_str(&format!("{operation:<width$}"));
+    }
+    lines.join("\n") + "\n"
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Mul,
+    Add,
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "*" => Ok(Operation::Mul),
+            "+" => Ok(Operation::Add),
+            _ => Err(format!("Invalid operation: {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Worksheet(pub Vec<(Operation, Vec<usize>)>);
+impl Worksheet {
+    pub fn evaluate(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(operation, numbers)| {
+                numbers.iter().fold(
+                    match operation {
+                        Operation::Add => 0,
+                        Operation::Mul => 1,
+                    },
+                    |total, num| match operation {
+                        Operation::Mul => total * num,
+                        Operation::Add => total + num,
+                    },
+                )
+            })
+            .sum()
+    }
+}
+
+/// Reads both worksheets from the problems, which are the columns of the input separated by
+/// blank columns, each with its operation on the last line.
+fn parse_input(input: &str) -> Result<Worksheets, ParseError> {
+    let problems = Span::new(input).columns()?;
+    if problems.is_empty() {
+        return Err(Span::new(input).error("Empty worksheet"));
+    }
+
+    let (mut rows, mut columns) = (vec![], vec![]);
+    for problem in problems {
+        let Some((operation_line, number_lines)) = problem.split_last() else {
+            unreachable!("a problem has a span on each of the input's lines");
+        };
+        let symbol = operation_line.trim();
+        let operation =
+            Operation::try_from(symbol.as_str()).map_err(|message| symbol.error(message))?;
+
+        // Part one reads each line of the problem as a number.
+        let numbers = number_lines
+            .iter()
+            .map(|line| line.trim().int())
+            .collect::<Result<_, _>>()?;
+        rows.push((operation, numbers));
+
+        // Part two reads each column of digits top to bottom. Lines may have lost their trailing
+        // spaces, and `columns` only allows ASCII, so bytes are characters.
+        let width = problem.iter().map(Span::len).max().unwrap_or_default();
+        let mut numbers = vec![];
+        for x in 0..width {
+            let mut digits = String::new();
+            for line in number_lines.iter().filter(|line| x < line.len()) {
+                match line.as_str().as_bytes()[x] {
+                    b' ' => {}
+                    c if c.is_ascii_digit() => digits.push(c as char),
+                    c => {
+                        let at = line.slice(x..x + 1);
+                        return Err(at.error(format!("Invalid digit: {}", c as char)));
+                    }
+                }
+            }
+            if digits.is_empty() {
+                return Err(operation_line.error("Problem has an empty column"));
+            }
+            let number = digits
+                .parse()
+                .map_err(|_| symbol.error(format!("Number out of range: {digits}")))?;
+            numbers.push(number);
+        }
+        columns.push((operation, numbers));
+    }
+    Ok(Worksheets {
+        rows: Worksheet(rows),
+        columns: Worksheet(columns),
+    })
+}
+
+/// The same homework read both ways, since the two parts disagree on how the numbers are laid out.
+#[derive(Debug, Clone)]
+pub struct Worksheets {
+    pub rows: Worksheet,
+    pub columns: Worksheet,
+}
+
+impl DayImpl for Day {
+    type Input = Worksheets;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let result: usize = input.rows.evaluate();
+        result.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let result = input.columns.evaluate();
+        result.into()
+    }
+}
@@ -0,0 +1,68 @@
+//! Reads the worksheet as a grid of characters, splitting problems on all-blank columns.
+
+use crate::answer::Answer;
+
+/// Every problem's operator and its columns of characters, top to bottom, without the operator
+/// row.
+fn problems(input: &str) -> Vec<(char, Vec<Vec<char>>)> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines.iter().map(|line| line.len()).max().unwrap();
+    let grid: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| format!("{line:<width$}").chars().collect())
+        .collect();
+    let (operators, numbers) = grid.split_last().unwrap();
+
+    let mut problems = vec![];
+    let mut columns: Vec<Vec<char>> = vec![];
+    let mut operator = ' ';
+    for x in 0..=width {
+        let column: Option<Vec<char>> = (x < width).then(|| numbers.iter().map(|row| row[x]).collect());
+        match column {
+            Some(column) if column.iter().any(|c| *c != ' ') || operators[x] != ' ' => {
+                if operators[x] != ' ' {
+                    operator = operators[x];
+                }
+                columns.push(column);
+            }
+            _ if !columns.is_empty() => problems.push((operator, std::mem::take(&mut columns))),
+            _ => {}
+        }
+    }
+    problems
+}
+
+fn evaluate(operator: char, numbers: impl Iterator<Item = u64>) -> u64 {
+    match operator {
+        '+' => numbers.sum(),
+        '*' => numbers.product(),
+        _ => panic!("unknown operator {operator}"),
+    }
+}
+
+fn number(digits: impl Iterator<Item = char>) -> Option<u64> {
+    let digits: String = digits.filter(|c| *c != ' ').collect();
+    digits.parse().ok()
+}
+
+pub fn part_one(input: &str) -> Answer {
+    let total: u64 = problems(input)
+        .iter()
+        .map(|(operator, columns)| {
+            let rows = (0..columns[0].len()).map(|y| columns.iter().map(move |column| column[y]));
+            evaluate(*operator, rows.filter_map(number))
+        })
+        .sum();
+    total.into()
+}
+
+pub fn part_two(input: &str) -> Answer {
+    let total: u64 = problems(input)
+        .iter()
+        .map(|(operator, columns)| {
+            let columns = columns.iter().map(|column| column.iter().copied());
+            evaluate(*operator, columns.filter_map(number))
+        })
+        .sum();
+    total.into()
+}
@@ -0,0 +1,181 @@
+use std::fmt::Display;
+
+use crate::{
+    DayImpl,
+    answer::Answer,
+    differential::Reference,
+    generate::{Generator, Rng},
+    grid::{Grid, Point},
+    memo::Memo,
+    parse::ParseError,
+    trace::{self, Colour, Frame},
+};
+
+mod reference;
+
+pub struct Day;
+
+register_day!(7, "Laboratories", generator = GENERATOR, reference = REFERENCE);
+
+const REFERENCE: Reference = Reference {
+    part_one: reference::part_one,
+    part_two: reference::part_two,
+};
+
+const GENERATOR: Generator = Generator {
+    default_size: 70,
+    generate,
+};
+
+/// A manifold with `size` rows of splitters below the start, each splitter on a cell a beam can
+/// reach, laid out like the puzzle's: a widening triangle with some splitters missing. The number
+/// of timelines grows exponentially, overflowing a `u64` at around 80 rows.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let center = size + 1;
+    let empty = ".".repeat(width) + "\n";
+
+    let mut input = empty.clone();
+    input.replace_range(center..=center, "S");
+    input += &empty;
+    for row in 0..size {
+        let mut line: Vec<u8> = empty.clone().into_bytes();
+        for column in (center - row..=center + row).step_by(2) {
+            if row == 0 || rng.chance(0.8) {
+                line[column] = b'^';
+            }
+        }
+        input += &String::from_utf8(line).expect("manifold is ASCII");
+        input += &empty;
+    }
+    input
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Splitter,
+    Beam,
+    Start,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cell::Empty),
+            '^' => Ok(Cell::Splitter),
+            '|' => Ok(Cell::Beam),
+            'S' => Ok(Cell::Start),
+            c => Err(format!("Invalid cell: {c}")),
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Empty => '.',
+            Cell::Splitter => '^',
+            Cell::Beam => '|',
+            Cell::Start => 'S',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TachyonManifold {
+    pub map: Grid<Cell>,
+    pub start_position: Point,
+}
+
+impl Display for TachyonManifold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+fn parse_input(input: &str) -> Result<TachyonManifold, ParseError> {
+    let map: Grid<Cell> = Grid::parse(input)?;
+    let Some(start_position) = map.find(|cell| *cell == Cell::Start) else {
+        return Err(ParseError::at(
+            input,
+            input.trim(),
+            "Manifold has no start (S)",
+        ));
+    };
+    if start_position.y + 1 >= map.height() {
+        let last_line = input.trim().lines().last().unwrap_or_default();
+        return Err(ParseError::at(
+            input,
+            last_line,
+            "Start (S) must not be on the last row",
+        ));
+    }
+    Ok(TachyonManifold {
+        map,
+        start_position,
+    })
+}
+
+impl DayImpl for Day {
+    type Input = TachyonManifold;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let mut m = input.clone();
+        let start = m.start_position;
+        m.map[Point::new(start.x, start.y + 1)] = Cell::Beam;
+
+        let mut split_count = 0;
+        for y in start.y + 1..m.map.height() - 1 {
+            for x in 0..m.map.width() {
+                if m.map[Point::new(x, y)] == Cell::Beam {
+                    match m.map[Point::new(x, y + 1)] {
+                        Cell::Empty => m.map[Point::new(x, y + 1)] = Cell::Beam,
+                        Cell::Splitter => {
+                            m.map[Point::new(x - 1, y + 1)] = Cell::Beam;
+                            m.map[Point::new(x + 1, y + 1)] = Cell::Beam;
+                            split_count += 1;
+                        }
+                        _ => continue,
+                    }
+                }
+            }
+            trace::frame(|| {
+                Frame::new(format!("row {y}, {split_count} splits"), m.to_string())
+                    .colour('|', Colour::Cyan)
+                    .colour('^', Colour::Yellow)
+                    .colour('S', Colour::Green)
+            });
+        }
+
+        split_count.into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let start = input.start_position;
+
+        // A beam heading down from a point splits into a timeline for each way out of the map.
+        let mut timelines = Memo::new();
+        let count = timelines.solve(Point::new(start.x, start.y + 1), |&head, timelines| {
+            let below = Point::new(head.x, head.y + 1);
+            match input.map.get(below) {
+                None => Some(1usize),
+                Some(Cell::Splitter) => {
+                    let left = timelines.get(Point::new(head.x - 1, below.y));
+                    let right = timelines.get(Point::new(head.x + 1, below.y));
+                    Some(left? + right?)
+                }
+                Some(_) => timelines.get(below),
+            }
+        });
+        timelines.report("beam heads");
+
+        count.into()
+    }
+}
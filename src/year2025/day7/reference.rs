@@ -0,0 +1,36 @@
+//! Moves the beams down one row at a time, counting how many timelines are in each column.
+
+use crate::answer::Answer;
+
+/// The number of splitters hit, and the number of timelines leaving the bottom.
+fn simulate(input: &str) -> (usize, usize) {
+    let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    let width = rows.iter().map(|row| row.len()).max().unwrap();
+    let start = rows.iter().position(|row| row.contains(&b'S')).unwrap();
+
+    let mut timelines = vec![0; width];
+    timelines[rows[start].iter().position(|c| *c == b'S').unwrap()] = 1;
+    let mut splits = 0;
+    for row in &rows[start + 1..] {
+        let mut next = vec![0; width];
+        for (x, count) in timelines.iter().enumerate().filter(|(_, count)| **count > 0) {
+            if row.get(x) == Some(&b'^') {
+                splits += 1;
+                next[x - 1] += count;
+                next[x + 1] += count;
+            } else {
+                next[x] += count;
+            }
+        }
+        timelines = next;
+    }
+    (splits, timelines.iter().sum())
+}
+
+pub fn part_one(input: &str) -> Answer {
+    simulate(input).0.into()
+}
+
+pub fn part_two(input: &str) -> Answer {
+    simulate(input).1.into()
+}
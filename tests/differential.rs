@@ -0,0 +1,14 @@
+//! Every solver must agree with its day's reference solution on small generated inputs.
+
+use aoc2025::{Part, differential, registry};
+
+#[test]
+fn solvers_agree_with_references() {
+    for day in registry::all() {
+        for part in [Part::One, Part::Two] {
+            if let Err(counterexample) = differential::check(day, part, 0..20, 8) {
+                panic!("\n{counterexample}");
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! One test per year, day and part, generated by `build.rs` from each day's `example_input` and the
+//! expected answers in its `example_answers` sidecar.
+
+#![allow(
+    non_snake_case,
+    reason = "test names carry example names, encoded without collisions but not always snake case"
+)]
+
+use aoc2025::{Answer, Part, registry};
+
+fn check(year: u16, day: u8, part: u8, input: &str, expected: &str) {
+    let solver = registry::year(year)
+        .and_then(|year| year.get(day))
+        .expect("day is registered")
+        .solver();
+    let part = match part {
+        1 => Part::One,
+        _ => Part::Two,
+    };
+    let parsed = solver
+        .parse(input)
+        .unwrap_or_else(|err| panic!("\n{}", err.with_day(day)));
+    let expected: Answer = expected.parse().unwrap();
+    assert_eq!(solver.solve(parsed.as_ref(), part), expected);
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
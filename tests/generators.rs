@@ -0,0 +1,39 @@
+//! Every input generator must produce inputs its day can solve, and reproduce them from a seed.
+
+use aoc2025::{Part, generate::Rng, registry};
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in registry::all() {
+        let Some(generator) = day.generator else {
+            continue;
+        };
+        let solver = day.solver();
+        for seed in 0..5 {
+            let input = (generator.generate)(&mut Rng::new(seed), 3);
+            let parsed = solver.parse(&input).unwrap_or_else(|err| {
+                panic!("\nseed {seed}:\n{}\n{input}", err.with_day(day.number))
+            });
+            for part in [Part::One, Part::Two] {
+                assert!(
+                    solver.solve(parsed.as_ref(), part).is_solved(),
+                    "day {} part {} has no answer for seed {seed}:\n{input}",
+                    day.number,
+                    part.number()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for day in registry::all() {
+        let Some(generator) = day.generator else {
+            continue;
+        };
+        let generate = |seed| (generator.generate)(&mut Rng::new(seed), 20);
+        assert_eq!(generate(7), generate(7), "day {}", day.number);
+        assert_ne!(generate(7), generate(8), "day {}", day.number);
+    }
+}
@@ -0,0 +1,21 @@
+//! Inputs the days must reject with a parse error instead of panicking while solving.
+
+use aoc2025::registry;
+
+fn parse_error(day: u8, input: &str) -> String {
+    let solver = registry::year(2025)
+        .and_then(|year| year.get(day))
+        .expect("day is registered")
+        .solver();
+    match solver.parse(input) {
+        Ok(_) => panic!("day {day} accepted {input:?}"),
+        Err(err) => err.message,
+    }
+}
+
+#[test]
+fn ranges_ending_at_the_largest_id_are_rejected() {
+    let message = "Range must end below 18446744073709551615";
+    assert_eq!(parse_error(2, "5-18446744073709551615\n"), message);
+    assert_eq!(parse_error(5, "5-18446744073709551615\n\n1\n"), message);
+}